@@ -0,0 +1,534 @@
+//! Derive macro for [pin-projections](https://docs.rs/pin-projections).
+//!
+//! This crate is an implementation detail, use it through the `derive` feature of
+//! `pin-projections` which re-exports [`PinProjections`](macro@PinProjections).
+
+use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Generates pin projections for members marked with `#[pin]`, `#[pin(mut)]` or `#[unpin]`.
+///
+/// See the documentation of the re-export in `pin-projections` for details.
+#[proc_macro_derive(PinProjections, attributes(pin, unpin))]
+pub fn derive_pin_projections(input: TokenStream) -> TokenStream {
+    match Struct::parse(input) {
+        Ok(item) => item.projections(),
+        Err(error) => error.into_compile_error(),
+    }
+}
+
+/// A compile error at some span.
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: impl Into<String>) -> Self {
+        Error {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Expands to `::core::compile_error!("message")` located at the span of the error.
+    fn into_compile_error(self) -> TokenStream {
+        let mut message = TokenStream::from(TokenTree::Literal(proc_macro::Literal::string(
+            &self.message,
+        )));
+        message = respan(message, self.span);
+
+        let mut tokens = Vec::new();
+        for segment in ["core", "compile_error"] {
+            let mut colons = Punct::new(':', Spacing::Joint);
+            colons.set_span(self.span);
+            tokens.push(TokenTree::Punct(colons));
+            let mut colons = Punct::new(':', Spacing::Alone);
+            colons.set_span(self.span);
+            tokens.push(TokenTree::Punct(colons));
+            tokens.push(TokenTree::Ident(Ident::new(segment, self.span)));
+        }
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(self.span);
+        tokens.push(TokenTree::Punct(bang));
+        let mut group = Group::new(Delimiter::Brace, message);
+        group.set_span(self.span);
+        tokens.push(TokenTree::Group(group));
+        tokens.into_iter().collect()
+    }
+}
+
+fn respan(stream: TokenStream, span: Span) -> TokenStream {
+    stream
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+/// How a member is projected.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Policy {
+    /// No projection.
+    None,
+    /// `#[pin]`, a `Pin<&T>` projection.
+    Pin,
+    /// `#[pin(mut)]`, `Pin<&T>` and `Pin<&mut T>` projections.
+    PinMut,
+    /// `#[unpin]`, `&T` and `&mut T` projections.
+    Unpin,
+}
+
+/// A member of the struct.
+struct Field {
+    vis: TokenStream,
+    name: Ident,
+    ty: TokenStream,
+    policy: Policy,
+}
+
+/// The parsed struct definition.
+struct Struct {
+    name: Ident,
+    /// `#[pin(PinnedDrop)]`, `Drop` is implemented with `pinned_drop!`.
+    pinned_drop: bool,
+    impl_generics: TokenStream,
+    type_generics: TokenStream,
+    where_clause: TokenStream,
+    fields: Vec<Field>,
+}
+
+/// Cursor over a flat list of tokens.
+struct Cursor {
+    tokens: Vec<TokenTree>,
+    pos: usize,
+}
+
+impl Cursor {
+    fn new(stream: TokenStream) -> Self {
+        Cursor {
+            tokens: stream.into_iter().collect(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&TokenTree> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&TokenTree> {
+        self.tokens.get(self.pos + n)
+    }
+
+    fn next(&mut self) -> Option<TokenTree> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn span(&self) -> Span {
+        self.peek()
+            .or_else(|| self.tokens.last())
+            .map_or_else(Span::call_site, TokenTree::span)
+    }
+
+    fn is_punct(&self, ch: char) -> bool {
+        matches!(self.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+    }
+
+    fn is_ident(&self, name: &str) -> bool {
+        matches!(self.peek(), Some(TokenTree::Ident(ident)) if ident.to_string() == name)
+    }
+
+    fn is_group(&self, delimiter: Delimiter) -> bool {
+        matches!(self.peek(), Some(TokenTree::Group(group)) if group.delimiter() == delimiter)
+    }
+
+    /// Consumes outer attributes, returns the bracketed groups.
+    fn attributes(&mut self) -> Vec<Group> {
+        let mut attributes = Vec::new();
+        while self.is_punct('#') {
+            match self.peek_nth(1) {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                    attributes.push(group.clone());
+                    self.pos += 2;
+                }
+                _ => break,
+            }
+        }
+        attributes
+    }
+
+    /// Consumes an optional visibility.
+    fn visibility(&mut self) -> TokenStream {
+        let mut vis = TokenStream::new();
+        if self.is_ident("pub") {
+            vis.extend(self.next());
+            if self.is_group(Delimiter::Parenthesis) {
+                vis.extend(self.next());
+            }
+        }
+        vis
+    }
+
+    fn ident(&mut self, what: &str) -> Result<Ident, Error> {
+        match self.peek() {
+            Some(TokenTree::Ident(ident)) => {
+                let ident = ident.clone();
+                self.pos += 1;
+                Ok(ident)
+            }
+            _ => Err(Error::new(self.span(), format!("expected {what}"))),
+        }
+    }
+
+    /// Consumes tokens up to a top level occurrence of `end` (not consumed) or the end of
+    /// input. Tracks `<>` nesting, `->` is not taken as closing angle bracket.
+    fn until(&mut self, end: char) -> Vec<TokenTree> {
+        let mut depth = 0usize;
+        let mut tokens = Vec::new();
+        while let Some(token) = self.peek() {
+            if let TokenTree::Punct(punct) = token {
+                let ch = punct.as_char();
+                if ch == end && depth == 0 {
+                    break;
+                }
+                let arrow = matches!(
+                    tokens.last(),
+                    Some(TokenTree::Punct(prev)) if prev.as_char() == '-' && prev.spacing() == Spacing::Joint
+                );
+                match ch {
+                    '<' => depth += 1,
+                    '>' if !arrow => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            tokens.extend(self.next());
+        }
+        tokens
+    }
+}
+
+/// Splits tokens at top level commas, honoring `<>` nesting.
+fn split_commas(stream: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut cursor = Cursor::new(stream);
+    let mut parts = Vec::new();
+    while !cursor.is_empty() {
+        let part = cursor.until(',');
+        cursor.next();
+        if !part.is_empty() {
+            parts.push(part);
+        }
+    }
+    parts
+}
+
+impl Struct {
+    fn parse(input: TokenStream) -> Result<Self, Error> {
+        let mut cursor = Cursor::new(input);
+        let mut pinned_drop = false;
+        for attribute in cursor.attributes() {
+            Self::check_repr(&attribute)?;
+            pinned_drop |= Self::check_pinned_drop(&attribute)?;
+        }
+        cursor.visibility();
+
+        if !cursor.is_ident("struct") {
+            return Err(Error::new(
+                cursor.span(),
+                "PinProjections can only be derived for structs",
+            ));
+        }
+        cursor.next();
+        let name = cursor.ident("struct name")?;
+
+        let mut impl_generics = TokenStream::new();
+        let mut type_generics = TokenStream::new();
+        if cursor.is_punct('<') {
+            cursor.next();
+            let generics: TokenStream = cursor.until('>').into_iter().collect();
+            cursor.next();
+            let mut impl_params = Vec::new();
+            let mut type_params = Vec::new();
+            for param in split_commas(generics) {
+                let (impl_param, type_param) = Self::generic_param(param)?;
+                impl_params.push(impl_param);
+                type_params.push(type_param);
+            }
+            impl_generics = format!("<{}>", impl_params.join(", ")).parse().unwrap();
+            type_generics = format!("<{}>", type_params.join(", ")).parse().unwrap();
+        }
+
+        let mut where_clause = TokenStream::new();
+        if cursor.is_ident("where") {
+            while !cursor.is_empty() && !cursor.is_group(Delimiter::Brace) {
+                where_clause.extend(cursor.next());
+            }
+        }
+
+        let fields = match cursor.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+                Self::fields(group.stream())?
+            }
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ';' => Vec::new(),
+            Some(token) => {
+                return Err(Error::new(
+                    token.span(),
+                    "PinProjections needs a struct with named members",
+                ))
+            }
+            None => return Err(Error::new(cursor.span(), "expected struct body")),
+        };
+
+        Ok(Struct {
+            name,
+            pinned_drop,
+            impl_generics,
+            type_generics,
+            where_clause,
+            fields,
+        })
+    }
+
+    /// Rejects `#[repr(packed)]`, projections would create references to unaligned members.
+    fn check_repr(attribute: &Group) -> Result<(), Error> {
+        let mut cursor = Cursor::new(attribute.stream());
+        if !cursor.is_ident("repr") {
+            return Ok(());
+        }
+        cursor.next();
+        if let Some(TokenTree::Group(group)) = cursor.next() {
+            for token in group.stream() {
+                if matches!(&token, TokenTree::Ident(ident) if ident.to_string() == "packed") {
+                    return Err(Error::new(
+                        token.span(),
+                        "PinProjections can't be derived for `#[repr(packed)]` structs, \
+                         their members may be unaligned and moved on drop",
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses `#[pin(PinnedDrop)]` on the struct, other `#[pin]` forms belong to members.
+    fn check_pinned_drop(attribute: &Group) -> Result<bool, Error> {
+        let mut cursor = Cursor::new(attribute.stream());
+        if !cursor.is_ident("pin") && !cursor.is_ident("unpin") {
+            return Ok(false);
+        }
+        cursor.next();
+        match cursor.next() {
+            Some(TokenTree::Group(group))
+                if group.delimiter() == Delimiter::Parenthesis
+                    && group.stream().to_string() == "PinnedDrop"
+                    && cursor.is_empty() =>
+            {
+                Ok(true)
+            }
+            _ => Err(Error::new(
+                attribute.span(),
+                "expected `#[pin(PinnedDrop)]` on the struct",
+            )),
+        }
+    }
+
+    /// Returns the parameter for the `impl<...>` (without default) and for the type arguments.
+    fn generic_param(param: Vec<TokenTree>) -> Result<(String, String), Error> {
+        let span = param[0].span();
+        let mut cursor = Cursor::new(param.into_iter().collect());
+        if cursor.is_punct('\'') {
+            let tokens: TokenStream = cursor.tokens.iter().cloned().collect();
+            let lifetime: TokenStream = cursor.tokens[..2].iter().cloned().collect();
+            return Ok((tokens.to_string(), lifetime.to_string()));
+        }
+        if cursor.is_ident("const") {
+            cursor.next();
+        }
+        let name = cursor
+            .ident("generic parameter")
+            .map_err(|_| Error::new(span, "unsupported generic parameter"))?;
+        cursor.pos = 0;
+        let without_default: TokenStream = cursor.until('=').into_iter().collect();
+        Ok((without_default.to_string(), name.to_string()))
+    }
+
+    fn fields(stream: TokenStream) -> Result<Vec<Field>, Error> {
+        let mut fields = Vec::new();
+        for tokens in split_commas(stream) {
+            let mut cursor = Cursor::new(tokens.into_iter().collect());
+            let mut policy = Policy::None;
+            for attribute in cursor.attributes() {
+                let attribute_policy = Self::policy(&attribute)?;
+                if attribute_policy != Policy::None {
+                    if policy != Policy::None {
+                        return Err(Error::new(attribute.span(), "duplicate pinning attribute"));
+                    }
+                    policy = attribute_policy;
+                }
+            }
+            let vis = cursor.visibility();
+            let name = cursor.ident("member name")?;
+            if !cursor.is_punct(':') {
+                return Err(Error::new(cursor.span(), "expected `:`"));
+            }
+            cursor.next();
+            let ty: TokenStream = cursor.tokens[cursor.pos..].iter().cloned().collect();
+            fields.push(Field {
+                vis,
+                name,
+                ty,
+                policy,
+            });
+        }
+        Ok(fields)
+    }
+
+    /// Parses the contents of a `#[...]` member attribute.
+    fn policy(attribute: &Group) -> Result<Policy, Error> {
+        let mut cursor = Cursor::new(attribute.stream());
+        let policy = if cursor.is_ident("pin") {
+            cursor.next();
+            match cursor.next() {
+                None => Policy::Pin,
+                Some(TokenTree::Group(group))
+                    if group.delimiter() == Delimiter::Parenthesis
+                        && group.stream().to_string() == "mut" =>
+                {
+                    Policy::PinMut
+                }
+                Some(token) => {
+                    return Err(Error::new(
+                        token.span(),
+                        "expected `#[pin]` or `#[pin(mut)]`",
+                    ))
+                }
+            }
+        } else if cursor.is_ident("unpin") {
+            cursor.next();
+            Policy::Unpin
+        } else {
+            return Ok(Policy::None);
+        };
+        if !cursor.is_empty() {
+            return Err(Error::new(cursor.span(), "unexpected tokens in attribute"));
+        }
+        Ok(policy)
+    }
+
+    /// Generates the `impl` block containing the projections.
+    fn projections(&self) -> TokenStream {
+        let mut body = String::new();
+        for field in &self.fields {
+            let vis = field.vis.to_string();
+            let member = field.name.to_string();
+            let function = member.strip_prefix("r#").unwrap_or(&member);
+            let ty = field.ty.to_string();
+            let project = "::pin_projections::project!";
+            match field.policy {
+                Policy::None => {}
+                Policy::Pin => {
+                    body += &format!("{project}({vis} {member} -> Pin<& {ty}>);\n");
+                }
+                Policy::PinMut => {
+                    body += &format!("{project}({vis} {member} -> Pin<& {ty}>);\n");
+                    body += &format!(
+                        "{project}({vis} {member} as {function}_mut() -> Pin<&mut {ty}>);\n"
+                    );
+                }
+                Policy::Unpin => {
+                    body += &format!("{project}({vis} {member} -> & {ty});\n");
+                    body +=
+                        &format!("{project}({vis} {member} as {function}_mut() -> &mut {ty});\n");
+                }
+            }
+        }
+        format!(
+            "impl {} {} {} {} {{\n{}}}\n{}",
+            self.impl_generics,
+            self.name,
+            self.type_generics,
+            self.where_clause,
+            body,
+            self.checks()
+        )
+        .parse()
+        .unwrap()
+    }
+
+    /// Turns the structural pinning requirements into compile errors when members are
+    /// pinned: `Self` is only `Unpin` when the pinned members are, conflicting with a manual
+    /// `Unpin` implementation, and `Drop` must be implemented with `pinned_drop!`.
+    fn checks(&self) -> String {
+        if !self
+            .fields
+            .iter()
+            .any(|field| matches!(field.policy, Policy::Pin | Policy::PinMut))
+        {
+            return String::new();
+        }
+
+        let where_clause = self.where_clause.to_string();
+        let bounds = where_clause
+            .trim_start_matches("where")
+            .trim()
+            .trim_end_matches(',');
+        let target = format!("{} {}", self.name, self.type_generics);
+
+        // all members but the #[unpin] ones may be projected pinned
+        let mut predicates: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|field| field.policy != Policy::Unpin)
+            .map(|field| format!("__Pinned<'__pin, {}>: ::core::marker::Unpin", field.ty))
+            .collect();
+        if !bounds.is_empty() {
+            predicates.push(bounds.to_string());
+        }
+        let unpin_generics = match self.impl_generics.to_string().strip_prefix('<') {
+            Some(params) => format!("<'__pin, {params}"),
+            None => "<'__pin>".to_string(),
+        };
+
+        let drop_check = if self.pinned_drop {
+            format!(
+                "trait ImplementsPinnedDrop {{}}
+                impl {} ImplementsPinnedDrop for {target}
+                where
+                    Self: ::pin_projections::pinned_drop::PinnedDrop,
+                    {bounds}
+                {{}}",
+                self.impl_generics
+            )
+        } else {
+            format!(
+                "trait MustNotImplDrop {{}}
+                #[allow(drop_bounds)]
+                impl<T: ::core::ops::Drop> MustNotImplDrop for T {{}}
+                impl {} MustNotImplDrop for {target} {where_clause} {{}}",
+                self.impl_generics
+            )
+        };
+
+        format!(
+            "const _: () = {{
+                pub struct __Pinned<'__pin, T: ?::core::marker::Sized>(
+                    ::core::marker::PhantomData<&'__pin ()>,
+                    ::core::marker::PhantomData<T>,
+                );
+                impl {unpin_generics} ::core::marker::Unpin for {target}
+                where
+                    {}
+                {{}}
+
+                {drop_check}
+            }};",
+            predicates.join(", ")
+        )
+    }
+}
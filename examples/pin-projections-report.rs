@@ -0,0 +1,66 @@
+//! Counts the projections generated by `project!` per module, the markers don't name the
+//! type implementing a projection.
+//!
+//! Build the crate to inspect with the `report` feature of pin-projections enabled, then run
+//!
+//! ```text
+//! cargo run --example pin-projections-report -- target/debug/deps/libmycrate-*.rlib
+//! ```
+//!
+//! Any compiled artifact (rlib, object file, executable) can be scanned. Note that the linker
+//! may discard markers of unused projections from executables.
+
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+
+const MAGIC: &[u8] = b"\0pin-projections-report:";
+
+/// Returns all `module::function` markers found in `data`.
+fn markers(data: &[u8]) -> Vec<String> {
+    let mut found = Vec::new();
+    let mut rest = data;
+    while let Some(start) = rest.windows(MAGIC.len()).position(|w| w == MAGIC) {
+        rest = &rest[start + MAGIC.len()..];
+        if let Some(end) = rest.iter().position(|&b| b == 0) {
+            found.push(String::from_utf8_lossy(&rest[..end]).into_owned());
+            rest = &rest[end..];
+        }
+    }
+    found
+}
+
+fn main() -> ExitCode {
+    let files: Vec<String> = std::env::args().skip(1).collect();
+    if files.is_empty() {
+        eprintln!("usage: pin-projections-report FILE...");
+        return ExitCode::FAILURE;
+    }
+
+    let mut per_module: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for file in &files {
+        let data = match std::fs::read(file) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("{file}: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        for marker in markers(&data) {
+            let (module, function) = marker.rsplit_once("::").unwrap_or(("", &marker));
+            per_module
+                .entry(module.to_owned())
+                .or_default()
+                .push(function.to_owned());
+        }
+    }
+
+    let mut total = 0;
+    for (module, functions) in &mut per_module {
+        functions.sort();
+        functions.dedup();
+        total += functions.len();
+        println!("{:6} {module}: {}", functions.len(), functions.join(", "));
+    }
+    println!("{total:6} projections in {} modules", per_module.len());
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,307 @@
+//! Traits for writing generic code over projections.
+
+use core::pin::Pin;
+
+/// Types that can project to a pinned `T`.
+///
+/// Usually implemented with the [`impl_as_pin!`] macro.
+///
+/// [`impl_as_pin!`]: crate::impl_as_pin
+pub trait AsPinRef<T: ?Sized> {
+    /// Projects to the pinned `T`.
+    fn as_pin_ref(self: Pin<&Self>) -> Pin<&T>;
+}
+
+/// Types that can project to a mutable pinned `T`.
+///
+/// Usually implemented with the [`impl_as_pin!`] macro.
+///
+/// [`impl_as_pin!`]: crate::impl_as_pin
+pub trait AsPinMut<T: ?Sized>: AsPinRef<T> {
+    /// Projects to the mutable pinned `T`.
+    fn as_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T>;
+}
+
+/// Types with a projection struct borrowing all their members at once.
+///
+/// Usually implemented with the [`impl_pin_project!`] macro.
+///
+/// [`impl_pin_project!`]: crate::impl_pin_project
+pub trait PinProject {
+    /// The projection, borrowing `Self` for `'a`.
+    type Proj<'a>
+    where
+        Self: 'a;
+
+    /// Projects to all members at once.
+    fn project(self: Pin<&mut Self>) -> Self::Proj<'_>;
+}
+
+/// Implements [`AsPinRef`] and [`AsPinMut`] for structurally pinned members of a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// impl_as_pin!($TYPE { $MEMBER: $MEMBER_TYPE, ... })
+/// ```
+///
+/// Each member implements both traits with `T = $MEMBER_TYPE`. Since the traits are generic
+/// over the target type, a struct can implement them for several members as long as their
+/// types differ.
+///
+/// # SAFETY
+///
+/// The members must be structurally pinned, see [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::access::{AsPinMut, AsPinRef};
+/// use pin_projections::impl_as_pin;
+/// use std::pin::Pin;
+///
+/// struct Timer {
+///     deadline: u64,
+/// }
+///
+/// struct Task {
+///     id: u32,
+///     timer: Timer,
+/// }
+/// impl_as_pin!(Task { timer: Timer });
+///
+/// struct Job {
+///     timer: Timer,
+/// }
+/// impl_as_pin!(Job { timer: Timer });
+///
+/// fn deadline(timed: Pin<&impl AsPinRef<Timer>>) -> u64 {
+///     timed.as_pin_ref().deadline
+/// }
+///
+/// fn postpone(timed: Pin<&mut impl AsPinMut<Timer>>) {
+///     unsafe { Pin::get_unchecked_mut(timed.as_pin_mut()).deadline += 10 };
+/// }
+///
+/// let mut task = Box::pin(Task { id: 1, timer: Timer { deadline: 100 } });
+/// let job = Box::pin(Job { timer: Timer { deadline: 200 } });
+/// postpone(task.as_mut());
+/// assert_eq!(deadline(task.as_ref()) + deadline(job.as_ref()), 310);
+/// ```
+#[macro_export]
+macro_rules! impl_as_pin {
+    ($S:ty { $($M:ident : $T:ty),* $(,)? }) => {
+        $(
+            impl $crate::access::AsPinRef<$T> for $S {
+                #[inline]
+                fn as_pin_ref(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&$T> {
+                    unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.$M) }
+                }
+            }
+
+            impl $crate::access::AsPinMut<$T> for $S {
+                #[inline]
+                fn as_pin_mut(self: ::core::pin::Pin<&mut Self>) -> ::core::pin::Pin<&mut $T> {
+                    unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) }
+                }
+            }
+        )*
+    };
+}
+
+/// Defines a projection struct and implements [`PinProject`] with it.
+///
+/// The syntax is:
+///
+/// ```text
+/// impl_pin_project! {
+///     [$ATTRIBUTES] [pub] struct $PROJECTION for $HOST { [pinned] $MEMBER: $TYPE, ... }
+/// }
+/// ```
+///
+/// This defines `struct $PROJECTION<'a>` with one member of the same name for each MEMBER,
+/// with the same visibility as PROJECTION. Members prefixed with `pinned` become
+/// `Pin<&'a mut $TYPE>`, all others `&'a mut $TYPE`. HOST gets an implementation of
+/// [`PinProject`] with `Proj<'a> = $PROJECTION<'a>`. Members of HOST that are not listed
+/// are not accessible through the projection.
+///
+/// # SAFETY
+///
+/// The members prefixed with `pinned` must be structurally pinned, all others must not be,
+/// see [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::access::PinProject;
+/// use pin_projections::impl_pin_project;
+/// use std::future::Future;
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll};
+///
+/// struct Counted<F> {
+///     future: F,
+///     polls: u32,
+/// }
+///
+/// impl_pin_project! {
+///     struct CountedProj for Counted<std::future::Ready<u8>> {
+///         pinned future: std::future::Ready<u8>,
+///         polls: u32,
+///     }
+/// }
+///
+/// fn project_any<T: PinProject>(this: Pin<&mut T>) -> T::Proj<'_> {
+///     this.project()
+/// }
+///
+/// let mut counted = Box::pin(Counted { future: std::future::ready(42), polls: 0 });
+/// let proj = project_any(counted.as_mut());
+/// *proj.polls += 1;
+/// let mut cx = Context::from_waker(std::task::Waker::noop());
+/// assert_eq!(proj.future.poll(&mut cx), Poll::Ready(42));
+/// assert_eq!(counted.polls, 1);
+/// ```
+#[macro_export]
+macro_rules! impl_pin_project {
+    (@ty pinned $T:ty) => { ::core::pin::Pin<&'__a mut $T> };
+    (@ty unpinned $T:ty) => { &'__a mut $T };
+    (@value pinned $this:ident $M:ident) => {
+        unsafe { ::core::pin::Pin::new_unchecked(&mut $this.$M) }
+    };
+    (@value unpinned $this:ident $M:ident) => {
+        &mut $this.$M
+    };
+    (
+        @fields [$(#[$attr:meta])*] [$P:vis] $PROJ:ident $HOST:ty
+        [$({$policy:ident $M:ident $T:ty})*]
+    ) => {
+        $(#[$attr])*
+        $P struct $PROJ<'__a> {
+            $($P $M: $crate::impl_pin_project!(@ty $policy $T),)*
+        }
+
+        impl $crate::access::PinProject for $HOST {
+            type Proj<'__a>
+                = $PROJ<'__a>
+            where
+                Self: '__a;
+
+            #[inline]
+            fn project(self: ::core::pin::Pin<&mut Self>) -> $PROJ<'_> {
+                let this = unsafe { ::core::pin::Pin::get_unchecked_mut(self) };
+                $PROJ {
+                    $($M: $crate::impl_pin_project!(@value $policy this $M),)*
+                }
+            }
+        }
+    };
+    (
+        @fields $attr:tt $P:tt $PROJ:ident $HOST:ty [$($done:tt)*]
+        pinned $M:ident : $T:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_pin_project!(
+            @fields $attr $P $PROJ $HOST [$($done)* {pinned $M $T}] $($($rest)*)?
+        );
+    };
+    (
+        @fields $attr:tt $P:tt $PROJ:ident $HOST:ty [$($done:tt)*]
+        $M:ident : $T:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::impl_pin_project!(
+            @fields $attr $P $PROJ $HOST [$($done)* {unpinned $M $T}] $($($rest)*)?
+        );
+    };
+    (
+        $(#[$attr:meta])*
+        $P:vis struct $PROJ:ident for $HOST:ty { $($fields:tt)* }
+    ) => {
+        $crate::impl_pin_project!(@fields [$(#[$attr])*] [$P] $PROJ $HOST [] $($fields)*);
+    };
+}
+
+/// Delegates to the single inner member of a wrapper.
+///
+/// The syntax is:
+///
+/// ```text
+/// impl_pin_deref!([impl<$GENERICS>] $TYPE => not_pinned $MEMBER: $MEMBER_TYPE)
+/// impl_pin_deref!([impl<$GENERICS>] [pub] $TYPE => $MEMBER: $MEMBER_TYPE)
+/// ```
+///
+/// With `not_pinned` TYPE implements `Deref` and `DerefMut` with `Target = $MEMBER_TYPE`.
+/// Otherwise MEMBER is structurally pinned and TYPE gets the projections
+/// `as_inner(self: Pin<&mut Self>) -> Pin<&mut $MEMBER_TYPE>` and
+/// `as_inner_ref(self: Pin<&Self>) -> Pin<&$MEMBER_TYPE>` with the given visibility, as
+/// `DerefMut` would allow moving the member out of a pinned wrapper. GENERICS are type
+/// parameters with an optional single bound like `F: Future`.
+///
+/// # SAFETY
+///
+/// MEMBER must be structurally pinned or not as declared, see [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::impl_pin_deref;
+/// use std::future::Future;
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll};
+///
+/// struct Labeled {
+///     label: String,
+/// }
+/// impl_pin_deref!(Labeled => not_pinned label: String);
+///
+/// struct Logged<F> {
+///     inner: F,
+/// }
+/// impl_pin_deref!(impl<F: Future> pub Logged<F> => inner: F);
+///
+/// impl<F: Future> Future for Logged<F> {
+///     type Output = F::Output;
+///
+///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+///         self.as_inner().poll(cx)
+///     }
+/// }
+///
+/// let mut labeled = Labeled { label: "task".into() };
+/// labeled.push('s');
+/// assert_eq!(labeled.len(), 5);
+///
+/// let mut logged = Box::pin(Logged { inner: std::future::ready(1) });
+/// let mut cx = Context::from_waker(std::task::Waker::noop());
+/// assert_eq!(logged.as_mut().poll(&mut cx), Poll::Ready(1));
+/// ```
+#[macro_export]
+macro_rules! impl_pin_deref {
+    (@impl [$($G:tt)*] $S:ty => not_pinned $M:ident : $T:ty) => {
+        impl<$($G)*> ::core::ops::Deref for $S {
+            type Target = $T;
+
+            #[inline]
+            fn deref(&self) -> &$T {
+                &self.$M
+            }
+        }
+
+        impl<$($G)*> ::core::ops::DerefMut for $S {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut $T {
+                &mut self.$M
+            }
+        }
+    };
+    (@impl [$($G:tt)*] $P:vis $S:ty => $M:ident : $T:ty) => {
+        impl<$($G)*> $S {
+            $crate::project!($P $M as as_inner_ref(), as_inner() -> Pin<&(mut) $T>);
+        }
+    };
+    (impl<$($G:ident $(: $B:path)?),* $(,)?> $($rest:tt)*) => {
+        $crate::impl_pin_deref!(@impl [$($G $(: $B)?),*] $($rest)*);
+    };
+    ($($rest:tt)*) => {
+        $crate::impl_pin_deref!(@impl [] $($rest)*);
+    };
+}
@@ -0,0 +1,20 @@
+//! Elements of arrays selected by a const generic index.
+
+/// Selects element `I` of an array, it is a compile error when `I` is out of bounds.
+pub struct ArrayElement<const I: usize>;
+
+impl<const I: usize> ArrayElement<I> {
+    /// Returns element `I` of `array`.
+    #[inline]
+    pub fn get<T, const N: usize>(array: &[T; N]) -> &T {
+        const { assert!(I < N, "array element index out of bounds") };
+        &array[I]
+    }
+
+    /// Returns element `I` of `array` for writing.
+    #[inline]
+    pub fn get_mut<T, const N: usize>(array: &mut [T; N]) -> &mut T {
+        const { assert!(I < N, "array element index out of bounds") };
+        &mut array[I]
+    }
+}
@@ -0,0 +1,48 @@
+//! Async lock projections for `tokio::sync::Mutex` members.
+//!
+//! With the `tokio` feature the `async MutexGuard` form of `project!` calls into
+//! `::tokio::sync::Mutex`, the crate using it must depend on `tokio`.
+
+/// Locks a `tokio::sync::Mutex` for the `async MutexGuard` form.
+///
+/// ```
+/// use pin_projections::project;
+/// use tokio::sync::Mutex;
+///
+/// struct Shared {
+///     state: Mutex<u32>,
+/// }
+///
+/// impl Shared {
+///     project!(pub state as lock_state() -> async MutexGuard<u32>);
+/// }
+///
+/// let shared = Box::pin(Shared { state: Mutex::new(1) });
+/// futures::executor::block_on(async {
+///     *shared.as_ref().lock_state().await += 1;
+///     assert_eq!(*shared.as_ref().lock_state().await, 2);
+/// });
+/// ```
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tokio {
+    (guard $T:ty) => {
+        ::tokio::sync::MutexGuard<'_, $T>
+    };
+    (lock $member:expr) => {
+        ::tokio::sync::Mutex::lock(&$member).await
+    };
+}
+
+#[cfg(not(feature = "tokio"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tokio {
+    (guard $T:ty) => {
+        ()
+    };
+    (lock $member:expr) => {
+        ::core::compile_error!("project!: `async MutexGuard` needs the `tokio` feature")
+    };
+}
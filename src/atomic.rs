@@ -0,0 +1,188 @@
+//! Operations on atomic members of pinned objects.
+
+use core::fmt;
+use core::sync::atomic::Ordering;
+
+/// Atomic types that can be accessed through [`AtomicOps`].
+///
+/// Implemented for the atomic types of `core::sync::atomic` that the target supports. This
+/// trait is sealed.
+pub trait Atomic: sealed::Sealed {
+    /// The type of the value stored in the atomic.
+    type Value: Copy;
+
+    #[doc(hidden)]
+    fn load(&self, order: Ordering) -> Self::Value;
+
+    #[doc(hidden)]
+    fn store(&self, value: Self::Value, order: Ordering);
+
+    #[doc(hidden)]
+    fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    #[doc(hidden)]
+    fn compare_exchange(
+        &self,
+        current: Self::Value,
+        new: Self::Value,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::Value, Self::Value>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! atomic {
+    ($($(#[$cfg:meta])* $A:ty => $V:ty $(, <$T:ident>)?;)*) => {$(
+        $(#[$cfg])*
+        impl$(<$T>)? sealed::Sealed for $A {}
+
+        $(#[$cfg])*
+        impl$(<$T>)? Atomic for $A {
+            type Value = $V;
+
+            #[inline]
+            fn load(&self, order: Ordering) -> $V {
+                <$A>::load(self, order)
+            }
+
+            #[inline]
+            fn store(&self, value: $V, order: Ordering) {
+                <$A>::store(self, value, order)
+            }
+
+            #[inline]
+            fn swap(&self, value: $V, order: Ordering) -> $V {
+                <$A>::swap(self, value, order)
+            }
+
+            #[inline]
+            fn compare_exchange(
+                &self,
+                current: $V,
+                new: $V,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$V, $V> {
+                <$A>::compare_exchange(self, current, new, success, failure)
+            }
+        }
+    )*};
+}
+
+atomic! {
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicBool => bool;
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicI8 => i8;
+    #[cfg(target_has_atomic = "8")]
+    core::sync::atomic::AtomicU8 => u8;
+    #[cfg(target_has_atomic = "16")]
+    core::sync::atomic::AtomicI16 => i16;
+    #[cfg(target_has_atomic = "16")]
+    core::sync::atomic::AtomicU16 => u16;
+    #[cfg(target_has_atomic = "32")]
+    core::sync::atomic::AtomicI32 => i32;
+    #[cfg(target_has_atomic = "32")]
+    core::sync::atomic::AtomicU32 => u32;
+    #[cfg(target_has_atomic = "64")]
+    core::sync::atomic::AtomicI64 => i64;
+    #[cfg(target_has_atomic = "64")]
+    core::sync::atomic::AtomicU64 => u64;
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicIsize => isize;
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicUsize => usize;
+    #[cfg(target_has_atomic = "ptr")]
+    core::sync::atomic::AtomicPtr<T> => *mut T, <T>;
+}
+
+/// Accessor for an atomic member, returned by the `atomic` forms of
+/// [`project!`](crate::project).
+///
+/// Only the value operations are exposed, the atomic itself can't be moved or replaced.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::project;
+/// use std::pin::Pin;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// struct Node {
+///     refcount: AtomicUsize,
+/// }
+///
+/// impl Node {
+///     project!(refcount as refcount_ops() -> atomic AtomicUsize);
+/// }
+///
+/// let node = Box::pin(Node { refcount: AtomicUsize::new(1) });
+/// let refcount = node.as_ref().refcount_ops();
+/// refcount.store(2, Ordering::Release);
+/// assert_eq!(refcount.compare_exchange(2, 3, Ordering::AcqRel, Ordering::Acquire), Ok(2));
+/// assert_eq!(refcount.load(Ordering::Acquire), 3);
+/// ```
+pub struct AtomicOps<'a, A: Atomic> {
+    atomic: &'a A,
+}
+
+impl<'a, A: Atomic> AtomicOps<'a, A> {
+    #[doc(hidden)]
+    #[inline]
+    pub fn new(atomic: &'a A) -> Self {
+        Self { atomic }
+    }
+
+    /// Loads the value with the given memory `order`.
+    #[inline]
+    pub fn load(&self, order: Ordering) -> A::Value {
+        self.atomic.load(order)
+    }
+
+    /// Stores `value` with the given memory `order`.
+    #[inline]
+    pub fn store(&self, value: A::Value, order: Ordering) {
+        self.atomic.store(value, order)
+    }
+
+    /// Stores `value` with the given memory `order` and returns the previous value.
+    #[inline]
+    pub fn swap(&self, value: A::Value, order: Ordering) -> A::Value {
+        self.atomic.swap(value, order)
+    }
+
+    /// Stores `new` if the value is `current`, see `AtomicUsize::compare_exchange()` for the
+    /// orderings. Returns the previous value, `Ok` when it was replaced.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: A::Value,
+        new: A::Value,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<A::Value, A::Value> {
+        self.atomic.compare_exchange(current, new, success, failure)
+    }
+}
+
+impl<A: Atomic> Clone for AtomicOps<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: Atomic> Copy for AtomicOps<'_, A> {}
+
+impl<A: Atomic> fmt::Debug for AtomicOps<'_, A>
+where
+    A::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AtomicOps")
+            .field(&self.load(Ordering::Relaxed))
+            .finish()
+    }
+}
@@ -0,0 +1,109 @@
+//! Calling projections directly on `Pin<Box<T>>` owners.
+
+/// Defines an extension trait that forwards projections to `Pin<Box<$TYPE>>`.
+///
+/// The syntax is:
+///
+/// ```text
+/// pin_box_ext! {
+///     [$ATTRIBUTES] [pub] trait $TRAIT for $TYPE {
+///         [$ATTRIBUTES] fn $FUNCTION(&self [, $ARGUMENTS]) [-> $RESULT];
+///         [$ATTRIBUTES] fn $FUNCTION(&mut self [, $ARGUMENTS]) [-> $RESULT];
+///         ...
+///     }
+/// }
+/// ```
+///
+/// Each FUNCTION must be an existing projection (or any other method) of TYPE taking
+/// `self: Pin<&Self>` for `&self` or `self: Pin<&mut Self>` for `&mut self` and the given
+/// ARGUMENTS. The trait is implemented for `Pin<Box<$TYPE>>` by calling these through
+/// `as_ref()` or `as_mut()`.
+///
+/// This macro is only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::{pin_box_ext, project};
+/// use std::pin::Pin;
+///
+/// struct Example {
+///     pinned: u32,
+///     counter: u32,
+/// }
+///
+/// impl Example {
+///     project!(pinned -> Pin<&u32>);
+///     project!(counter as counter_mut() -> &mut u32);
+///     project!(counter as set_counter(u32));
+/// }
+///
+/// pin_box_ext! {
+///     trait BoxedExample for Example {
+///         fn pinned(&self) -> Pin<&u32>;
+///         fn counter_mut(&mut self) -> &mut u32;
+///         fn set_counter(&mut self, from: u32);
+///     }
+/// }
+///
+/// let mut boxed: Pin<Box<Example>> = Box::pin(Example { pinned: 1, counter: 2 });
+/// *boxed.counter_mut() += 1;
+/// assert_eq!(*boxed.pinned(), 1);
+/// boxed.set_counter(boxed.counter + 1);
+/// assert_eq!(boxed.counter, 4);
+/// ```
+#[macro_export]
+macro_rules! pin_box_ext {
+    (@decl) => {};
+    (@decl
+        $(#[$fattr:meta])*
+        fn $N:ident(&self $(, $A:ident : $AT:ty)* $(,)?) $(-> $R:ty)?;
+        $($rest:tt)*
+    ) => {
+        $(#[$fattr])*
+        fn $N(&self $(, $A: $AT)*) $(-> $R)?;
+        $crate::pin_box_ext!(@decl $($rest)*);
+    };
+    (@decl
+        $(#[$fattr:meta])*
+        fn $N:ident(&mut self $(, $A:ident : $AT:ty)* $(,)?) $(-> $R:ty)?;
+        $($rest:tt)*
+    ) => {
+        $(#[$fattr])*
+        fn $N(&mut self $(, $A: $AT)*) $(-> $R)?;
+        $crate::pin_box_ext!(@decl $($rest)*);
+    };
+    (@impl $T:ty;) => {};
+    (@impl $T:ty;
+        $(#[$fattr:meta])*
+        fn $N:ident(&self $(, $A:ident : $AT:ty)* $(,)?) $(-> $R:ty)?;
+        $($rest:tt)*
+    ) => {
+        #[inline]
+        fn $N(&self $(, $A: $AT)*) $(-> $R)? {
+            <$T>::$N(::core::pin::Pin::as_ref(self) $(, $A)*)
+        }
+        $crate::pin_box_ext!(@impl $T; $($rest)*);
+    };
+    (@impl $T:ty;
+        $(#[$fattr:meta])*
+        fn $N:ident(&mut self $(, $A:ident : $AT:ty)* $(,)?) $(-> $R:ty)?;
+        $($rest:tt)*
+    ) => {
+        #[inline]
+        fn $N(&mut self $(, $A: $AT)*) $(-> $R)? {
+            <$T>::$N(::core::pin::Pin::as_mut(self) $(, $A)*)
+        }
+        $crate::pin_box_ext!(@impl $T; $($rest)*);
+    };
+    ($(#[$attr:meta])* $P:vis trait $EXT:ident for $T:ty { $($items:tt)* }) => {
+        $(#[$attr])*
+        $P trait $EXT {
+            $crate::pin_box_ext!(@decl $($items)*);
+        }
+
+        impl $EXT for ::core::pin::Pin<$crate::__alloc::boxed::Box<$T>> {
+            $crate::pin_box_ext!(@impl $T; $($items)*);
+        }
+    };
+}
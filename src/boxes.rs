@@ -0,0 +1,86 @@
+//! Projections through boxed members and receivers.
+//!
+//! With the `alloc` feature the `boxed Option<..>` forms of `project!` pin the content of a
+//! boxed optional member, the `box` forms take `Pin<Box<Self>>` receivers and the `rc` and
+//! `arc` forms take `Pin<Rc<Self>>` and `Pin<Arc<Self>>` receivers.
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_box {
+    (ref $member:expr, $T:ty) => {
+        ::core::option::Option::map(
+            <::core::option::Option<$crate::__alloc::boxed::Box<$T>>>::as_ref(&$member),
+            |boxed| unsafe { ::core::pin::Pin::new_unchecked(&**boxed) },
+        )
+    };
+    (mut $member:expr, $T:ty) => {
+        ::core::option::Option::map(
+            <::core::option::Option<$crate::__alloc::boxed::Box<$T>>>::as_mut(&mut $member),
+            |boxed| unsafe { ::core::pin::Pin::new_unchecked(&mut **boxed) },
+        )
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_box {
+    ($($input:tt)*) => {
+        ::core::compile_error!("project!: `boxed Option<..>` needs the `alloc` feature")
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_box {
+    () => {
+        ::core::pin::Pin<$crate::__alloc::boxed::Box<Self>>
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_box {
+    () => {
+        ::core::compile_error!("project!: `box` needs the `alloc` feature")
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_rc {
+    () => {
+        ::core::pin::Pin<$crate::__alloc::rc::Rc<Self>>
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_rc {
+    () => {
+        ::core::compile_error!("project!: `rc` needs the `alloc` feature")
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_arc {
+    () => {
+        ::core::pin::Pin<$crate::__alloc::sync::Arc<Self>>
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_arc {
+    () => {
+        ::core::compile_error!("project!: `arc` needs the `alloc` feature")
+    };
+}
@@ -0,0 +1,82 @@
+//! C callbacks from closures stored in pinned objects.
+
+/// Defines an accessor that turns a closure member into a C callback.
+///
+/// Accessors are written inside the `impl` block of a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// c_callback!([pub] $MEMBER: $TYPE as $FUNCTION($ARGUMENTS) [-> $RETURN])
+/// ```
+///
+/// The parameters are:
+///  - **MEMBER:** name of the member holding the closure.
+///  - **TYPE:** type of the member, it must implement `FnMut($ARGUMENTS) -> $RETURN`.
+///  - **FUNCTION:** name for the accessor.
+///  - **ARGUMENTS:** named arguments of the callback as in a function declaration.
+///  - **RETURN:** result of the callback, when not given it returns `()`.
+///
+/// The generated accessor takes `self: Pin<&mut Self>` and returns a pair of
+/// `unsafe extern "C" fn(*mut c_void, $ARGUMENTS) -> $RETURN` trampoline and the
+/// `*mut c_void` user data pointer that has to be passed to it.
+///
+/// # SAFETY
+///
+/// Because the object is pinned the user data pointer stays valid until the object is
+/// dropped. The caller has to ensure that the callback is not invoked after that and that it
+/// is not invoked concurrently or reentrant.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::c_callback;
+/// use std::ffi::c_void;
+/// use std::pin::Pin;
+///
+/// struct Handler<F> {
+///     on_event: F,
+/// }
+///
+/// impl<F: FnMut(u32) -> u32> Handler<F> {
+///     c_callback!(pub on_event: F as on_event_callback(event: u32) -> u32);
+/// }
+///
+/// // placeholder for a C function taking a callback
+/// extern "C" fn emit(cb: unsafe extern "C" fn(*mut c_void, u32) -> u32, data: *mut c_void) -> u32 {
+///     unsafe { cb(data, 20) + cb(data, 22) }
+/// }
+///
+/// let mut count = 0;
+/// let mut handler = Box::pin(Handler { on_event: |event| { count += 1; event } });
+/// let (cb, data) = handler.as_mut().on_event_callback();
+/// assert_eq!(emit(cb, data), 42);
+/// drop(handler);
+/// assert_eq!(count, 2);
+/// ```
+#[macro_export]
+macro_rules! c_callback {
+    ($P:vis $M:ident : $T:ty as $N:ident($($A:ident : $AT:ty),* $(,)?)) => {
+        $crate::c_callback!($P $M: $T as $N($($A: $AT),*) -> ());
+    };
+    ($P:vis $M:ident : $T:ty as $N:ident($($A:ident : $AT:ty),* $(,)?) -> $R:ty) => {
+        #[inline]
+        #[allow(clippy::type_complexity)]
+        $P fn $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> (
+            unsafe extern "C" fn(*mut ::core::ffi::c_void $(, $AT)*) -> $R,
+            *mut ::core::ffi::c_void,
+        ) {
+            unsafe extern "C" fn trampoline<C: ::core::ops::FnMut($($AT),*) -> $R>(
+                data: *mut ::core::ffi::c_void
+                $(, $A: $AT)*
+            ) -> $R {
+                unsafe { (*(data as *mut C))($($A),*) }
+            }
+
+            let data = unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M } as *mut $T;
+            (trampoline::<$T>, data as *mut ::core::ffi::c_void)
+        }
+    };
+}
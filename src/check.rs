@@ -0,0 +1,165 @@
+//! Debug checks that pinned objects don't move.
+//!
+//! With the `debug-checks` feature and debug assertions enabled, every `Pin<&mut Type>`
+//! projection of [`project!`](crate::project) on a struct implementing [`PinChecked`] records
+//! the address of the struct on first use and panics when a later projection sees a
+//! different one. This catches moves of supposedly pinned data, for example by a wrong
+//! `get_unchecked_mut()`. Otherwise [`PinCheck`] is zero sized and nothing is checked.
+
+use core::marker::PhantomPinned;
+#[cfg(all(feature = "debug-checks", debug_assertions))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Storage for the address of a pinned object, a member of the checked struct.
+///
+/// `PinCheck` is `!Unpin` and so is the struct containing it. An `Unpin` struct may move
+/// legally between projections, the check would panic then.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::check::PinCheck;
+/// use pin_projections::{pin_check, project};
+/// use std::pin::Pin;
+///
+/// struct Task {
+///     timer: u64,
+///     check: PinCheck,
+/// }
+/// pin_check!(Task { check });
+///
+/// impl Task {
+///     project!(timer as timer_mut() -> Pin<&mut u64>);
+/// }
+///
+/// let mut task = Box::pin(Task { timer: 0, check: PinCheck::new() });
+/// *task.as_mut().timer_mut() += 1;
+/// *task.as_mut().timer_mut() += 1;
+/// assert_eq!(task.timer, 2);
+/// ```
+///
+/// A struct with a check is not `Unpin`:
+///
+/// ```compile_fail
+/// # use pin_projections::check::PinCheck;
+/// struct Task {
+///     check: PinCheck,
+/// }
+///
+/// fn assert_unpin<T: Unpin>() {}
+/// assert_unpin::<Task>();
+/// ```
+#[derive(Debug, Default)]
+pub struct PinCheck {
+    #[cfg(all(feature = "debug-checks", debug_assertions))]
+    address: AtomicUsize,
+    _pinned: PhantomPinned,
+}
+
+impl PinCheck {
+    /// Creates a check that has not seen an address yet.
+    pub const fn new() -> Self {
+        PinCheck {
+            #[cfg(all(feature = "debug-checks", debug_assertions))]
+            address: AtomicUsize::new(0),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    /// Records the address of `host` on the first call, panics when it differs later on.
+    #[cfg_attr(
+        not(all(feature = "debug-checks", debug_assertions)),
+        allow(unused_variables)
+    )]
+    pub fn check<T: ?Sized>(&self, host: &T) {
+        #[cfg(all(feature = "debug-checks", debug_assertions))]
+        {
+            let address = host as *const T as *const () as usize;
+            if let Err(first) =
+                self.address
+                    .compare_exchange(0, address, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                assert!(
+                    first == address,
+                    "pinned object moved from {first:#x} to {address:#x}"
+                );
+            }
+        }
+    }
+}
+
+/// Structs with a [`PinCheck`] member.
+///
+/// Usually implemented with the [`pin_check!`] macro, generic structs implement it by hand.
+///
+/// [`pin_check!`]: crate::pin_check
+pub trait PinChecked {
+    /// Returns the check of `self`.
+    fn pin_check(&self) -> &PinCheck;
+}
+
+/// Implements [`PinChecked`] for a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// pin_check!($TYPE { $MEMBER })
+/// ```
+///
+/// MEMBER must be a [`PinCheck`]. The type must not depend on generic parameters.
+#[macro_export]
+macro_rules! pin_check {
+    ($T:ty { $M:ident }) => {
+        impl $crate::check::PinChecked for $T {
+            fn pin_check(&self) -> &$crate::check::PinCheck {
+                &self.$M
+            }
+        }
+    };
+}
+
+#[cfg(feature = "debug-checks")]
+#[doc(hidden)]
+pub struct __Probe<'a, T: ?Sized>(pub &'a T);
+
+#[cfg(feature = "debug-checks")]
+#[doc(hidden)]
+pub trait __Checked {
+    fn __pin_check(&self);
+}
+
+#[cfg(feature = "debug-checks")]
+impl<T: ?Sized + PinChecked> __Checked for __Probe<'_, T> {
+    fn __pin_check(&self) {
+        self.0.pin_check().check(self.0);
+    }
+}
+
+// selected by autoref when T is not PinChecked
+#[cfg(feature = "debug-checks")]
+#[doc(hidden)]
+pub trait __Unchecked {
+    fn __pin_check(&self) {}
+}
+
+#[cfg(feature = "debug-checks")]
+impl<T: ?Sized> __Unchecked for &__Probe<'_, T> {}
+
+#[cfg(feature = "debug-checks")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_check {
+    ($this:ident) => {{
+        #[allow(unused_imports)]
+        use $crate::check::{__Checked as _, __Unchecked as _};
+        (&$crate::check::__Probe(::core::pin::Pin::get_ref(::core::pin::Pin::as_ref(&$this))))
+            .__pin_check();
+    }};
+}
+
+#[cfg(not(feature = "debug-checks"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pin_check {
+    ($this:ident) => {};
+}
@@ -0,0 +1,87 @@
+//! Checkpoint and restore of the movable members of pinned objects.
+
+/// Defines a checkpoint struct for the `Unpin` members of a pinned object.
+///
+/// The syntax is:
+///
+/// ```text
+/// checkpoint! {
+///     [$ATTRIBUTES] [pub] struct $CHECKPOINT for $HOST { $MEMBER: $TYPE, ... }
+/// }
+/// ```
+///
+/// This defines the struct CHECKPOINT with a copy of each MEMBER. HOST gets the methods
+/// `fn checkpoint(self: Pin<&Self>) -> $CHECKPOINT` which clones the members and
+/// `fn restore(self: Pin<&mut Self>, checkpoint: $CHECKPOINT)` which moves them back, both
+/// with the same visibility as CHECKPOINT. All other members, especially the pinned ones, are
+/// not touched.
+///
+/// Every TYPE must be `Clone` and `Unpin`, it is a compile error otherwise.
+///
+/// # SAFETY
+///
+/// The members must not be structurally pinned, see [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::checkpoint;
+/// use std::marker::PhantomPinned;
+///
+/// struct Parser {
+///     position: usize,
+///     errors: Vec<String>,
+///     _pinned: PhantomPinned,
+/// }
+///
+/// checkpoint! {
+///     #[derive(Debug)]
+///     struct ParserCheckpoint for Parser { position: usize, errors: Vec<String> }
+/// }
+///
+/// let mut parser = Box::pin(Parser { position: 0, errors: Vec::new(), _pinned: PhantomPinned });
+/// let checkpoint = parser.as_ref().checkpoint();
+/// unsafe {
+///     let speculative = parser.as_mut().get_unchecked_mut();
+///     speculative.position = 10;
+///     speculative.errors.push("unexpected token".into());
+/// }
+/// parser.as_mut().restore(checkpoint);
+/// assert_eq!(parser.position, 0);
+/// assert!(parser.errors.is_empty());
+/// ```
+#[macro_export]
+macro_rules! checkpoint {
+    (
+        $(#[$attr:meta])*
+        $P:vis struct $CHECKPOINT:ident for $HOST:ty { $($M:ident : $T:ty),* $(,)? }
+    ) => {
+        $(#[$attr])*
+        $P struct $CHECKPOINT {
+            $(
+                #[doc = ::core::concat!("Saved value of `", ::core::stringify!($M), "`.")]
+                pub $M: $T,
+            )*
+        }
+
+        impl $HOST {
+            /// Saves the movable members.
+            #[inline]
+            $P fn checkpoint(self: ::core::pin::Pin<&Self>) -> $CHECKPOINT {
+                let this = ::core::pin::Pin::get_ref(self);
+                $CHECKPOINT {
+                    $($M: ::core::clone::Clone::clone(&this.$M),)*
+                }
+            }
+
+            /// Restores the movable members from a checkpoint.
+            #[inline]
+            $P fn restore(self: ::core::pin::Pin<&mut Self>, checkpoint: $CHECKPOINT) {
+                fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+                $(assert_unpin::<$T>();)*
+                let this = unsafe { ::core::pin::Pin::get_unchecked_mut(self) };
+                $(this.$M = checkpoint.$M;)*
+            }
+        }
+    };
+}
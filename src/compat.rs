@@ -0,0 +1,62 @@
+//! Drop-in replacements for the projection macros of `pin-utils`.
+
+/// Defines a structurally pinned projection, compatible with `pin_utils::unsafe_pinned!`.
+///
+/// `unsafe_pinned!(member: Type)` generates a private
+/// `fn member(self: Pin<&mut Self>) -> Pin<&mut Type>`. This is the same as
+/// `project!(member -> Pin<&mut Type>)`.
+///
+/// # SAFETY
+///
+/// The member must be structurally pinned, see [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::{unsafe_pinned, unsafe_unpinned};
+/// use std::pin::Pin;
+///
+/// struct Foo {
+///     pinned: u32,
+///     unpinned: u32,
+/// }
+///
+/// impl Foo {
+///     unsafe_pinned!(pinned: u32);
+///     unsafe_unpinned!(unpinned: u32);
+/// }
+///
+/// let mut foo = Box::pin(Foo { pinned: 1, unpinned: 2 });
+/// *foo.as_mut().pinned() = 10;
+/// *foo.as_mut().unpinned() = 20;
+/// assert_eq!((foo.pinned, foo.unpinned), (10, 20));
+/// ```
+#[macro_export]
+macro_rules! unsafe_pinned {
+    ($M:ident : $T:ty) => {
+        #[inline]
+        fn $M<'__a>(self: ::core::pin::Pin<&'__a mut Self>) -> ::core::pin::Pin<&'__a mut $T> {
+            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) }
+        }
+    };
+}
+
+/// Defines a not structurally pinned projection, compatible with
+/// `pin_utils::unsafe_unpinned!`.
+///
+/// `unsafe_unpinned!(member: Type)` generates a private
+/// `fn member(self: Pin<&mut Self>) -> &mut Type`. This is the same as
+/// `project!(member -> &mut Type)`.
+///
+/// # SAFETY
+///
+/// The member must not be structurally pinned, see [`project!`](crate::project).
+#[macro_export]
+macro_rules! unsafe_unpinned {
+    ($M:ident : $T:ty) => {
+        #[inline]
+        fn $M<'__a>(self: ::core::pin::Pin<&'__a mut Self>) -> &'__a mut $T {
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M }
+        }
+    };
+}
@@ -0,0 +1,69 @@
+//! Checking that every member has a declared pinning policy.
+
+/// Fails to compile unless every member of the struct is listed with a pinning policy.
+///
+/// Written inside the `impl` block of a struct, next to the projections.
+///
+/// The syntax is:
+///
+/// ```text
+/// project_complete!(Self { $MEMBER: pinned | unpinned, ... })
+/// ```
+///
+/// Each member of the struct has to be listed exactly once and declared as either `pinned`
+/// (structurally pinned) or `unpinned` (not structurally pinned). The check is done by an
+/// exhaustive destructuring of `Self` in a hidden function, adding a member to the struct
+/// without adding it here is a compile error.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::{project, project_complete};
+/// use std::pin::Pin;
+///
+/// struct Example {
+///     pinned: u32,
+///     counter: u32,
+/// }
+///
+/// impl Example {
+///     project_complete!(Self { pinned: pinned, counter: unpinned });
+///
+///     project!(pinned -> Pin<&mut u32>);
+///     project!(counter -> &mut u32);
+/// }
+/// ```
+///
+/// Forgetting a member fails:
+///
+/// ```compile_fail
+/// # use pin_projections::project_complete;
+/// struct Example {
+///     pinned: u32,
+///     counter: u32,
+/// }
+///
+/// impl Example {
+///     project_complete!(Self { pinned: pinned });
+/// }
+/// ```
+#[macro_export]
+macro_rules! project_complete {
+    (@policy pinned) => {};
+    (@policy unpinned) => {};
+    (@policy $other:tt) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown pinning policy `",
+            ::core::stringify!($other),
+            "`, expected `pinned` or `unpinned`"
+        ));
+    };
+    (Self { $($M:ident : $policy:tt),* $(,)? }) => {
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        fn __project_complete(&self) {
+            $($crate::project_complete!(@policy $policy);)*
+            let Self { $($M: _),* } = self;
+        }
+    };
+}
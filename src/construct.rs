@@ -0,0 +1,62 @@
+//! Constructors returning pinned smart pointers.
+
+/// Defines a constructor that returns a pinned smart pointer.
+///
+/// Constructors are written inside the `impl` block of a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// new_pinned!([pub] fn $FUNCTION($ARGUMENTS) -> Box { $INITIALIZERS })
+/// new_pinned!([pub] fn $FUNCTION($ARGUMENTS) -> Rc { $INITIALIZERS })
+/// new_pinned!([pub] fn $FUNCTION($ARGUMENTS) -> Arc { $INITIALIZERS })
+/// ```
+///
+/// The generated function takes the given arguments and returns `Pin<Box<Self>>`,
+/// `Pin<Rc<Self>>` or `Pin<Arc<Self>>`. The INITIALIZERS are member initializers as in a
+/// struct expression of `Self`.
+///
+/// This macro is only available with the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::{new_pinned, project};
+/// use std::pin::Pin;
+///
+/// struct Timer {
+///     deadline: u64,
+///     name: String,
+/// }
+///
+/// impl Timer {
+///     new_pinned!(pub fn new_pinned(deadline: u64) -> Box { deadline, name: String::from("timer") });
+///     new_pinned!(pub fn new_shared(deadline: u64) -> Arc { deadline, name: String::new() });
+///
+///     project!(deadline -> &u64);
+/// }
+///
+/// let timer = Timer::new_pinned(100);
+/// assert_eq!(*timer.as_ref().deadline(), 100);
+///
+/// let shared = Timer::new_shared(200);
+/// assert_eq!(*shared.as_ref().deadline(), 200);
+/// ```
+#[macro_export]
+macro_rules! new_pinned {
+    ($P:vis fn $N:ident($($A:ident : $AT:ty),* $(,)?) -> Box { $($init:tt)* }) => {
+        $P fn $N($($A: $AT),*) -> ::core::pin::Pin<$crate::__alloc::boxed::Box<Self>> {
+            $crate::__alloc::boxed::Box::pin(Self { $($init)* })
+        }
+    };
+    ($P:vis fn $N:ident($($A:ident : $AT:ty),* $(,)?) -> Rc { $($init:tt)* }) => {
+        $P fn $N($($A: $AT),*) -> ::core::pin::Pin<$crate::__alloc::rc::Rc<Self>> {
+            $crate::__alloc::rc::Rc::pin(Self { $($init)* })
+        }
+    };
+    ($P:vis fn $N:ident($($A:ident : $AT:ty),* $(,)?) -> Arc { $($init:tt)* }) => {
+        $P fn $N($($A: $AT),*) -> ::core::pin::Pin<$crate::__alloc::sync::Arc<Self>> {
+            $crate::__alloc::sync::Arc::pin(Self { $($init)* })
+        }
+    };
+}
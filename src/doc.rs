@@ -0,0 +1,123 @@
+//! Generated documentation of projections.
+//!
+//! Every function generated by `project!` is documented, either by the doc comments given
+//! in front of its declaration or by a synthesized summary naming the member, the receiver
+//! and how the member is pinned. Forms that don't pin the member structurally name their
+//! pinning, all other `Pin` projections are structurally pinned. This keeps `missing_docs`
+//! quiet for public projections.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __doc {
+    (@receiver $s:tt : ::core::pin::Pin<&$($L:lifetime)? mut Self> $(, $($rest:tt)*)?) => {
+        "`Pin<&mut Self>`"
+    };
+    (@receiver $s:tt : ::core::pin::Pin<&$($L:lifetime)? Self> $(, $($rest:tt)*)?) => {
+        "`Pin<&Self>`"
+    };
+    (@receiver $s:tt : &$($L:lifetime)? mut ::core::pin::Pin<&mut Self> $(, $($rest:tt)*)?) => {
+        "`&mut Pin<&mut Self>`"
+    };
+    (@receiver $s:tt : &$($L:lifetime)? ::core::pin::Pin<&mut Self> $(, $($rest:tt)*)?) => {
+        "`&Pin<&mut Self>`"
+    };
+    (@receiver $s:tt : &mut $c:tt::__pin_box!() $(, $($rest:tt)*)?) => {
+        "`&mut Pin<Box<Self>>`"
+    };
+    (@receiver $s:tt : &$c:tt::__pin_box!() $(, $($rest:tt)*)?) => {
+        "`&Pin<Box<Self>>`"
+    };
+    (@receiver $s:tt : $c:tt::__pin_box!() $(, $($rest:tt)*)?) => {
+        "`Pin<Box<Self>>`"
+    };
+    (@receiver $s:tt : &$c:tt::__pin_rc!() $(, $($rest:tt)*)?) => {
+        "`&Pin<Rc<Self>>`"
+    };
+    (@receiver $s:tt : &$c:tt::__pin_arc!() $(, $($rest:tt)*)?) => {
+        "`&Pin<Arc<Self>>`"
+    };
+    (@receiver $s:tt : &mut Self $(, $($rest:tt)*)?) => {
+        "`&mut Self`"
+    };
+    (@receiver $s:tt : &Self $(, $($rest:tt)*)?) => {
+        "`&Self`"
+    };
+    (@receiver $s:tt : $R:ty $(, $($rest:tt)*)?) => {
+        ::core::concat!("`", ::core::stringify!($R), "`")
+    };
+    (pinned mut self ($($params:tt)*) $T:ty) => {
+        ::core::concat!(
+            "Reborrows ",
+            $crate::__doc!(@receiver $($params)*),
+            " as `Pin<&mut Self>`."
+        )
+    };
+    (pinned self ($($params:tt)*) $T:ty) => {
+        ::core::concat!(
+            "Reborrows ",
+            $crate::__doc!(@receiver $($params)*),
+            " as `Pin<&Self>`."
+        )
+    };
+    (pinned mut $M:tt ($($params:tt)*) $T:ty $(, $pinning:literal)?) => {
+        $crate::__doc!(@projects $M ($($params)*)
+            ::core::concat!("Pin<&mut ", ::core::stringify!($T), ">"),
+            $crate::__doc!(@pinning $($pinning)?)
+        )
+    };
+    (pinned $M:tt ($($params:tt)*) $T:ty $(, $pinning:literal)?) => {
+        $crate::__doc!(@projects $M ($($params)*)
+            ::core::concat!("Pin<&", ::core::stringify!($T), ">"),
+            $crate::__doc!(@pinning $($pinning)?)
+        )
+    };
+    (@pinning) => {
+        "structurally pinned"
+    };
+    (@pinning $pinning:literal) => {
+        $pinning
+    };
+    (unpinned mut $M:tt ($($params:tt)*) $T:ty) => {
+        $crate::__doc!(@projects $M ($($params)*)
+            ::core::concat!("&mut ", ::core::stringify!($T)),
+            "not structurally pinned"
+        )
+    };
+    (unpinned $M:tt ($($params:tt)*) $T:ty) => {
+        $crate::__doc!(@projects $M ($($params)*)
+            ::core::concat!("&", ::core::stringify!($T)),
+            "not structurally pinned"
+        )
+    };
+    (@projects $M:tt ($($params:tt)*) $projected:expr, $pinning:expr) => {
+        ::core::concat!(
+            "Projects the member `",
+            ::core::stringify!($M),
+            "` of ",
+            $crate::__doc!(@receiver $($params)*),
+            " to `",
+            $projected,
+            "`, the member is ",
+            $pinning,
+            "."
+        )
+    };
+    ($M:tt ($($params:tt)*) -> $R:ty) => {
+        ::core::concat!(
+            "Projects the member `",
+            ::core::stringify!($M),
+            "` of ",
+            $crate::__doc!(@receiver $($params)*),
+            "."
+        )
+    };
+    ($M:tt ($($params:tt)*)) => {
+        ::core::concat!(
+            "Accesses the member `",
+            ::core::stringify!($M),
+            "` of ",
+            $crate::__doc!(@receiver $($params)*),
+            "."
+        )
+    };
+}
@@ -0,0 +1,141 @@
+//! Generated usage examples in the documentation of projections.
+//!
+//! With the `doc-examples` feature every function generated by a `project!` invocation
+//! starting with `example = path::to::Type;` gets an example section after its documentation
+//! showing how to call it. The example is derived from the declaration, so it stays accurate
+//! when the declaration changes. It is compiled as a `no_run` doctest of the crate defining
+//! the type: a hidden trait implemented for the type takes the parameters of the projection
+//! and the example calls the projection from there. The parameter types are resolved through
+//! a glob import of the module of the type. Invocations without `example` get no examples.
+
+#[cfg(feature = "doc-examples")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __doc_example {
+    (@call
+        [$pol:tt [[$($m:ident)+] [$($E:tt)+]]] $N:ident $U:tt [$($G:tt)*] [$($P:tt)*]
+        ($($A:ident),*) $recv:ident [$($via:tt)*] [$($D:tt)*] $item:item
+    ) => {
+        $crate::__policy! {
+            [$pol [[$($m)+] [$($E)+]]]
+            $($D)*
+            #[doc = ::core::concat!(
+                "# Example\n",
+                "\n",
+                "```no_run\n",
+                "# use ", ::core::stringify!($($m)::+), "::*;\n",
+                "# trait DocExample: Sized {\n",
+                "#     fn example<", ::core::stringify!($($G)*), ">(self, ",
+                ::core::stringify!($($P)*), ");\n",
+                "# }\n",
+                "# impl DocExample for ", ::core::stringify!($($E)+), " {\n",
+                "# fn example<", ::core::stringify!($($G)*), ">(self, ",
+                ::core::stringify!($($P)*), ") {\n",
+                "# let mut value = self;\n",
+                $crate::__doc_example!(@recv $recv),
+                $crate::__doc_example!(@line $U
+                    ::core::stringify!($recv), $(::core::stringify!($via),)* ::core::stringify!($N),
+                    $crate::__doc_example!(@turbofish $($G)*),
+                    "(", $crate::__doc_example!(@args $($A),*), ")"
+                ),
+                "# }\n",
+                "# }\n",
+                "```"
+            )]
+            $item
+        }
+    };
+    (@recv pinned) => {
+        "let mut pinned = Box::pin(value);\n"
+    };
+    (@recv value) => {
+        ""
+    };
+    (@line [$(async)? unsafe] $($part:expr),*) => {
+        ::core::concat!("unsafe { ", $($part,)* " };\n")
+    };
+    (@line [$(async)?] $($part:expr),*) => {
+        ::core::concat!($($part,)* ";\n")
+    };
+    (@turbofish const $I:ident : $T:ty) => {
+        ::core::concat!("::<", ::core::stringify!($I), ">")
+    };
+    (@turbofish $T:ident : $($bounds:tt)*) => {
+        ::core::concat!("::<", ::core::stringify!($T), ">")
+    };
+    (@turbofish $($G:tt)*) => {
+        ""
+    };
+    (@args) => {
+        ""
+    };
+    (@args $first:ident $(, $A:ident)*) => {
+        ::core::concat!(::core::stringify!($first) $(, ", ", ::core::stringify!($A))*)
+    };
+    // without a type there is nothing to call the projection on
+    ([$pol:tt []] $N:ident $U:tt $G:tt ($($params:tt)*) [$($D:tt)*] $item:item) => {
+        $crate::__policy! {
+            [$pol []]
+            $($D)*
+            $item
+        }
+    };
+    (
+        $O:tt $N:ident $U:tt $G:tt (
+            self: ::core::pin::Pin<&$($L:lifetime)? mut Self> $(, $A:ident : $AT:ty)* $(,)?
+        )
+        $D:tt $item:item
+    ) => {
+        $crate::__doc_example!(
+            @call $O $N $U $G [$($A: $AT),*] ($($A),*) pinned [.as_mut().] $D $item
+        );
+    };
+    (
+        $O:tt $N:ident $U:tt $G:tt (
+            self: ::core::pin::Pin<&$($L:lifetime)? Self> $(, $A:ident : $AT:ty)* $(,)?
+        )
+        $D:tt $item:item
+    ) => {
+        $crate::__doc_example!(
+            @call $O $N $U $G [$($A: $AT),*] ($($A),*) pinned [.as_ref().] $D $item
+        );
+    };
+    (
+        $O:tt $N:ident $U:tt $G:tt (
+            self: &$($L:lifetime)? $(mut)? ::core::pin::Pin<&mut Self>
+            $(, $A:ident : $AT:ty)* $(,)?
+        )
+        $D:tt $item:item
+    ) => {
+        $crate::__doc_example!(
+            @call $O $N $U $G [$($A: $AT),*] ($($A),*) pinned [.as_mut().] $D $item
+        );
+    };
+    (
+        $O:tt $N:ident $U:tt $G:tt (
+            self: &$($L:lifetime)? $(mut)? Self $(, $A:ident : $AT:ty)* $(,)?
+        )
+        $D:tt $item:item
+    ) => {
+        $crate::__doc_example!(@call $O $N $U $G [$($A: $AT),*] ($($A),*) value [.] $D $item);
+    };
+    ($O:tt $N:ident $U:tt $G:tt (self: Self $(, $A:ident : $AT:ty)* $(,)?) $D:tt $item:item) => {
+        $crate::__doc_example!(@call $O $N $U $G [$($A: $AT),*] ($($A),*) value [.] $D $item);
+    };
+    ($O:tt $N:ident $U:tt $G:tt (self: $R:ty $(, $A:ident : $AT:ty)* $(,)?) $D:tt $item:item) => {
+        $crate::__doc_example!(@call $O $N $U $G [$($A: $AT),*] ($($A),*) pinned [.] $D $item);
+    };
+}
+
+#[cfg(not(feature = "doc-examples"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __doc_example {
+    ($O:tt $N:ident $U:tt $G:tt ($($params:tt)*) [$($D:tt)*] $item:item) => {
+        $crate::__policy! {
+            $O
+            $($D)*
+            $item
+        }
+    };
+}
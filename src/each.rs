@@ -0,0 +1,106 @@
+//! Code generation over a list of pinned fields.
+
+/// Expands code once per structurally pinned field.
+///
+/// The syntax is:
+///
+/// ```text
+/// for_each_pinned_field!(macro $LIST { $MEMBER: $TYPE, ... })
+/// for_each_pinned_field!($LIST: $CALLBACK!($ARGUMENTS))
+/// for_each_pinned_field!($LIST: $PIN => |$FIELD| $BODY)
+/// ```
+///
+/// The first form declares the pinned fields of a struct once, it is written next to the
+/// struct definition and defines the macro `$LIST!` holding the fields. The other forms take
+/// that list, `$LIST!` is only used by them.
+///
+/// The second form invokes the macro CALLBACK as `$CALLBACK! { $ARGUMENTS $MEMBER: $TYPE }`
+/// for each MEMBER. It can be used wherever macros can be, for example to generate impls or
+/// match arms. The callback is called with braces, it must be an item or statement macro.
+///
+/// The third form is written inside a function. PIN is a `Pin<&mut Self>` expression, it is
+/// consumed, use `self.as_mut()` to keep using `self` afterwards. For each MEMBER the BODY is
+/// expanded with FIELD bound to `Pin<&mut $TYPE>`, these don't need to have the same type.
+/// The values of BODY are discarded.
+///
+/// # SAFETY
+///
+/// The members must be structurally pinned, see [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::for_each_pinned_field;
+/// use std::future::{ready, Future, Ready};
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// struct Join {
+///     first: Ready<u8>,
+///     second: Ready<u16>,
+/// }
+///
+/// for_each_pinned_field!(macro join_fields { first: Ready<u8>, second: Ready<u16> });
+///
+/// macro_rules! field_name {
+///     ($T:ty; $M:ident : $F:ty) => {
+///         impl $T {
+///             #[allow(non_upper_case_globals)]
+///             const $M: &'static str = ::core::stringify!($M);
+///         }
+///     };
+/// }
+///
+/// for_each_pinned_field!(join_fields: field_name!(Join;));
+///
+/// impl Join {
+///     fn poll_all(self: Pin<&mut Self>, cx: &mut Context<'_>) -> usize {
+///         let mut ready = 0;
+///         for_each_pinned_field!(join_fields: self => |field| {
+///             if field.poll(cx).is_ready() {
+///                 ready += 1;
+///             }
+///         });
+///         ready
+///     }
+/// }
+///
+/// let mut join = Box::pin(Join { first: ready(1), second: ready(2) });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(join.as_mut().poll_all(&mut cx), 2);
+/// assert_eq!(Join::second, "second");
+/// ```
+#[macro_export]
+macro_rules! for_each_pinned_field {
+    (@define ($d:tt) $L:ident { $($M:ident : $T:ty),* $(,)? }) => {
+        #[allow(unused_macros)]
+        macro_rules! $L {
+            ($d($d input:tt)*) => {
+                $crate::for_each_pinned_field!(@with [$($M: $T),*] $d($d input)*);
+            };
+        }
+    };
+    (@each $C:ident $args:tt) => {};
+    (@each $C:ident [$($args:tt)*] $M:ident : $T:ty $(, $($rest:tt)*)?) => {
+        $C! { $($args)* $M: $T }
+        $crate::for_each_pinned_field!(@each $C [$($args)*] $($($rest)*)?);
+    };
+    (@with [$($fields:tt)*] $C:ident!($($args:tt)*)) => {
+        $crate::for_each_pinned_field!(@each $C [$($args)*] $($fields)*);
+    };
+    (@with [$($M:ident : $T:ty),*] $pin:expr => |$field:ident| $body:expr) => {{
+        let pin: ::core::pin::Pin<&mut _> = $pin;
+        let this = unsafe { ::core::pin::Pin::get_unchecked_mut(pin) };
+        $({
+            let $field: ::core::pin::Pin<&mut $T> =
+                unsafe { ::core::pin::Pin::new_unchecked(&mut this.$M) };
+            $body;
+        })*
+    }};
+    (macro $L:ident { $($fields:tt)* }) => {
+        $crate::for_each_pinned_field!(@define ($) $L { $($fields)* });
+    };
+    ($L:ident : $($input:tt)*) => {
+        $L! { $($input)* }
+    };
+}
@@ -0,0 +1,131 @@
+//! Stable ABI accessor tables for dynamically loaded code.
+//!
+//! This module is only available with the `abi` feature.
+
+/// Versioned header placed at the start of every table generated by [`abi_table!`].
+///
+/// Loaders should check the header with [`AbiHeader::is_compatible()`] before using any
+/// entry of a table they did not compile themselves.
+///
+/// [`abi_table!`]: crate::abi_table
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbiHeader {
+    /// User defined version of the table layout.
+    pub version: u32,
+    /// Number of accessor entries following the header.
+    pub entries: u32,
+    /// Size of the whole table in bytes, including the header.
+    pub size: usize,
+}
+
+impl AbiHeader {
+    /// Creates a header for a table of type `T`.
+    pub const fn new<T>(version: u32, entries: u32) -> Self {
+        AbiHeader {
+            version,
+            entries,
+            size: core::mem::size_of::<T>(),
+        }
+    }
+
+    /// Returns true when a table with this header can be used as a table of type `T` with the
+    /// given version. Tables may grow by appending entries, thus the table may be larger than
+    /// `T`.
+    pub const fn is_compatible<T>(&self, version: u32) -> bool {
+        self.version == version && self.size >= core::mem::size_of::<T>()
+    }
+}
+
+/// Defines a `#[repr(C)]` table of raw pointer based accessor functions.
+///
+/// The syntax is:
+///
+/// ```text
+/// abi_table! {
+///     [$ATTRIBUTES] [pub] struct $TABLE for $HOST, version $VERSION {
+///         [$ATTRIBUTES] $MEMBER: $TYPE,
+///         ...
+///     }
+/// }
+/// ```
+///
+/// The generated struct starts with an [`AbiHeader`] followed by one
+/// `unsafe extern "C" fn(*mut $HOST) -> *mut $TYPE` entry per member. A ready to use table is
+/// available as the associated constant `$TABLE::TABLE`. Entries must only be appended to keep
+/// older plugins working, changing or removing entries requires a new version.
+///
+/// # SAFETY
+///
+/// The entries take and return raw pointers, calling them is unsafe. The host pointer must
+/// point to a valid object and the caller is responsible to uphold the pinning guarantees of
+/// the host object when using the returned pointers.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::abi_table;
+///
+/// pub struct Host {
+///     counter: u64,
+///     name: &'static str,
+/// }
+///
+/// abi_table! {
+///     pub struct HostAbi for Host, version 1 {
+///         counter: u64,
+///         name: &'static str,
+///     }
+/// }
+///
+/// let mut host = Box::pin(Host { counter: 42, name: "host" });
+/// let table = HostAbi::TABLE;
+/// assert!(table.header.is_compatible::<HostAbi>(1));
+/// assert_eq!(table.header.entries, 2);
+///
+/// let this: *mut Host = unsafe { host.as_mut().get_unchecked_mut() };
+/// unsafe {
+///     *(table.counter)(this) += 1;
+///     assert_eq!(*(table.name)(this), "host");
+/// }
+/// assert_eq!(host.counter, 43);
+/// ```
+#[macro_export]
+macro_rules! abi_table {
+    (
+        $(#[$attr:meta])*
+        $P:vis struct $TABLE:ident for $HOST:ty, version $V:literal {
+            $($(#[$fattr:meta])* $M:ident : $T:ty),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        $P struct $TABLE {
+            /// Versioned header of this table.
+            pub header: $crate::abi::AbiHeader,
+            $(
+                $(#[$fattr])*
+                pub $M: unsafe extern "C" fn(*mut $HOST) -> *mut $T,
+            )*
+        }
+
+        impl $TABLE {
+            /// The accessor table for the host type.
+            pub const TABLE: Self = $TABLE {
+                header: $crate::abi::AbiHeader::new::<$TABLE>(
+                    $V,
+                    <[&str]>::len(&[$(::core::stringify!($M)),*]) as u32,
+                ),
+                $(
+                    $M: {
+                        unsafe extern "C" fn accessor(this: *mut $HOST) -> *mut $T {
+                            unsafe { ::core::ptr::addr_of_mut!((*this).$M) }
+                        }
+                        accessor
+                    },
+                )*
+            };
+        }
+    };
+}
@@ -0,0 +1,90 @@
+//! Experimental support for prototyping async drop of pinned children.
+//!
+//! This module is only available with the `async-drop` feature. It follows the
+//! `poll_drop_ready` design from the async drop proposals and may change with them, it is
+//! not covered by semver guarantees.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Types that need to perform asynchronous cleanup before they are dropped.
+pub trait PollDropReady {
+    /// Drives the asynchronous cleanup. Returns `Poll::Ready(())` once the object is ready to
+    /// be dropped. Must keep returning `Poll::Ready(())` when called again after that.
+    fn poll_drop_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()>;
+}
+
+/// Implements [`PollDropReady`] by forwarding to pinned members.
+///
+/// The syntax is:
+///
+/// ```text
+/// poll_drop_ready!($TYPE { $MEMBER, ... })
+/// ```
+///
+/// The members are polled in the order given. A member is only polled after all members
+/// before it became ready, the generated function returns `Poll::Pending` as long as any
+/// member is pending.
+///
+/// # SAFETY
+///
+/// The members are projected as `Pin<&mut Member>` and must be structurally pinned.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::poll_drop_ready;
+/// use pin_projections::async_drop::PollDropReady;
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// struct Flush {
+///     pending: u32,
+/// }
+///
+/// impl PollDropReady for Flush {
+///     fn poll_drop_ready(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+///         if self.pending == 0 {
+///             Poll::Ready(())
+///         } else {
+///             self.pending -= 1;
+///             Poll::Pending
+///         }
+///     }
+/// }
+///
+/// struct Connection {
+///     first: Flush,
+///     second: Flush,
+/// }
+/// poll_drop_ready!(Connection { first, second });
+///
+/// let mut conn = Box::pin(Connection { first: Flush { pending: 1 }, second: Flush { pending: 1 } });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert!(conn.as_mut().poll_drop_ready(&mut cx).is_pending());
+/// assert!(conn.as_mut().poll_drop_ready(&mut cx).is_pending());
+/// assert!(conn.as_mut().poll_drop_ready(&mut cx).is_ready());
+/// ```
+#[macro_export]
+macro_rules! poll_drop_ready {
+    ($T:ty { $($M:ident),* $(,)? }) => {
+        impl $crate::async_drop::PollDropReady for $T {
+            fn poll_drop_ready(
+                mut self: ::core::pin::Pin<&mut Self>,
+                cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<()> {
+                $(
+                    if $crate::async_drop::PollDropReady::poll_drop_ready(
+                        unsafe { ::core::pin::Pin::map_unchecked_mut(self.as_mut(), |s| &mut s.$M) },
+                        cx,
+                    )
+                    .is_pending()
+                    {
+                        return ::core::task::Poll::Pending;
+                    }
+                )*
+                ::core::task::Poll::Ready(())
+            }
+        }
+    };
+}
@@ -0,0 +1,175 @@
+//! Generating `project!` invocations from build scripts.
+//!
+//! This module is only available with the `codegen` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use pin_projections::codegen::{Kind, Projection, Projections};
+//!
+//! let code = Projections::new("Example")
+//!     .projection(Projection::new("inner", "Inner", Kind::PinnedMut).public())
+//!     .projection(Projection::new("inner", "Inner", Kind::Pinned).function("inner_ref"))
+//!     .projection(Projection::new("count", "u64", Kind::SetMove).function("set_count"))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     code,
+//!     "impl Example {\n    \
+//!          ::pin_projections::project!(pub inner -> Pin<&mut Inner>);\n    \
+//!          ::pin_projections::project!(inner as inner_ref() -> Pin<&Inner>);\n    \
+//!          ::pin_projections::project!(count as set_count(u64));\n\
+//!      }\n"
+//! );
+//! ```
+
+use std::fmt;
+
+/// The kind of a projection, corresponds to the PROJECTION and FROM forms of `project!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// `Pin<&Type>`
+    Pinned,
+    /// `Pin<&mut Type>`
+    PinnedMut,
+    /// `&Type`
+    Ref,
+    /// `&mut Type`
+    Mut,
+    /// `Type`, a cloning getter.
+    Clone,
+    /// `(&Type)`, a cloning setter.
+    SetClone,
+    /// `(Type)`, a moving setter.
+    SetMove,
+    /// `not_pinned` with `&Type`
+    NotPinnedRef,
+    /// `not_pinned` with `&mut Type`
+    NotPinnedMut,
+}
+
+/// Description of a single `project!` invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Projection {
+    member: String,
+    ty: String,
+    kind: Kind,
+    function: Option<String>,
+    visibility: Option<String>,
+    unsafe_: bool,
+}
+
+impl Projection {
+    /// Creates a private, safe projection for a member of the given type.
+    pub fn new(member: impl Into<String>, ty: impl Into<String>, kind: Kind) -> Self {
+        Projection {
+            member: member.into(),
+            ty: ty.into(),
+            kind,
+            function: None,
+            visibility: None,
+            unsafe_: false,
+        }
+    }
+
+    /// Sets the name of the generated function, by default the member name is used.
+    /// Setters always need a function name.
+    pub fn function(mut self, function: impl Into<String>) -> Self {
+        self.function = Some(function.into());
+        self
+    }
+
+    /// Sets the visibility, e.g. `pub(crate)`.
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Makes the projection `pub`.
+    pub fn public(self) -> Self {
+        self.visibility("pub")
+    }
+
+    /// Generates an `unsafe fn`. Has no effect on `not_pinned` projections.
+    pub fn unsafe_fn(mut self) -> Self {
+        self.unsafe_ = true;
+        self
+    }
+}
+
+impl fmt::Display for Projection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("::pin_projections::project!(")?;
+        if let Some(visibility) = &self.visibility {
+            write!(f, "{visibility} ")?;
+        }
+        match self.kind {
+            Kind::NotPinnedRef | Kind::NotPinnedMut => f.write_str("not_pinned ")?,
+            _ if self.unsafe_ => f.write_str("unsafe ")?,
+            _ => {}
+        }
+        f.write_str(&self.member)?;
+        if let Some(function) = &self.function {
+            write!(f, " as {function}")?;
+        }
+        let ty = &self.ty;
+        match self.kind {
+            Kind::SetClone => write!(f, "(&{ty})")?,
+            Kind::SetMove => write!(f, "({ty})")?,
+            kind => {
+                if self.function.is_some() {
+                    f.write_str("()")?;
+                }
+                match kind {
+                    Kind::Pinned => write!(f, " -> Pin<&{ty}>")?,
+                    Kind::PinnedMut => write!(f, " -> Pin<&mut {ty}>")?,
+                    Kind::Ref | Kind::NotPinnedRef => write!(f, " -> &{ty}")?,
+                    Kind::Mut | Kind::NotPinnedMut => write!(f, " -> &mut {ty}")?,
+                    _ => write!(f, " -> {ty}")?,
+                }
+            }
+        }
+        f.write_str(");")
+    }
+}
+
+/// A set of projections for one type, displayed as an `impl` block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Projections {
+    generics: String,
+    target: String,
+    projections: Vec<Projection>,
+}
+
+impl Projections {
+    /// Creates an empty set of projections for the target type, e.g. `Wrapper<T>`.
+    pub fn new(target: impl Into<String>) -> Self {
+        Projections {
+            generics: String::new(),
+            target: target.into(),
+            projections: Vec::new(),
+        }
+    }
+
+    /// Sets the generic parameters of the `impl` block, e.g. `<T: Unpin>`.
+    pub fn generics(mut self, generics: impl Into<String>) -> Self {
+        self.generics = generics.into();
+        self
+    }
+
+    /// Adds a projection.
+    pub fn projection(mut self, projection: Projection) -> Self {
+        self.projections.push(projection);
+        self
+    }
+}
+
+impl fmt::Display for Projections {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "impl{} {} {{", self.generics, self.target)?;
+        for projection in &self.projections {
+            writeln!(f, "    {projection}")?;
+        }
+        writeln!(f, "}}")
+    }
+}
@@ -0,0 +1,93 @@
+//! Comparing the fields of pinned objects in tests.
+//!
+//! This module is only available with the `test-util` feature.
+
+use crate::__alloc::string::String;
+use crate::__alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+
+/// A field that differs between two objects compared by [`PinDiff::diff()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Name of the member.
+    pub name: &'static str,
+    /// `Debug` representation of the member in `self`.
+    pub left: String,
+    /// `Debug` representation of the member in `other`.
+    pub right: String,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} != {}", self.name, self.left, self.right)
+    }
+}
+
+/// Types that can compare their fields from pinned references.
+///
+/// Usually implemented with the [`pin_diff!`] macro.
+///
+/// [`pin_diff!`]: crate::pin_diff
+pub trait PinDiff {
+    /// Returns the declared fields that differ between `self` and `other`, in declaration
+    /// order. An empty result means the declared fields are equal.
+    fn diff(self: Pin<&Self>, other: Pin<&Self>) -> Vec<FieldDiff>;
+}
+
+/// Implements [`PinDiff`] for a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// pin_diff!($TYPE { $MEMBER, ... })
+/// ```
+///
+/// Each MEMBER must implement `PartialEq` and `Debug`, they are compared in the order given.
+/// Members that are not listed, like pinned children without these traits, are ignored.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::diff::{FieldDiff, PinDiff};
+/// use pin_projections::pin_diff;
+/// use std::marker::PhantomPinned;
+///
+/// struct Machine {
+///     state: &'static str,
+///     retries: u32,
+///     _pinned: PhantomPinned,
+/// }
+/// pin_diff!(Machine { state, retries });
+///
+/// let left = Box::pin(Machine { state: "idle", retries: 0, _pinned: PhantomPinned });
+/// let right = Box::pin(Machine { state: "idle", retries: 2, _pinned: PhantomPinned });
+/// let diff = left.as_ref().diff(right.as_ref());
+/// assert_eq!(diff, [FieldDiff { name: "retries", left: "0".into(), right: "2".into() }]);
+/// assert_eq!(diff[0].to_string(), "retries: 0 != 2");
+/// ```
+#[macro_export]
+macro_rules! pin_diff {
+    ($T:ty { $($M:ident),* $(,)? }) => {
+        impl $crate::diff::PinDiff for $T {
+            fn diff(
+                self: ::core::pin::Pin<&Self>,
+                other: ::core::pin::Pin<&Self>,
+            ) -> $crate::__alloc::vec::Vec<$crate::diff::FieldDiff> {
+                let (this, other) = (::core::pin::Pin::get_ref(self), ::core::pin::Pin::get_ref(other));
+                #[allow(unused_mut)]
+                let mut diffs = $crate::__alloc::vec::Vec::new();
+                $(
+                    if this.$M != other.$M {
+                        diffs.push($crate::diff::FieldDiff {
+                            name: ::core::stringify!($M),
+                            left: $crate::__alloc::format!("{:?}", this.$M),
+                            right: $crate::__alloc::format!("{:?}", other.$M),
+                        });
+                    }
+                )*
+                diffs
+            }
+        }
+    };
+}
@@ -0,0 +1,19 @@
+//! Runtime support types.
+//!
+//! Each submodule is behind its own cargo feature and re-exported at the crate root. Users
+//! of the macros only pay for what they enable.
+
+#[cfg(feature = "abi")]
+pub mod abi;
+
+#[cfg(feature = "async-drop")]
+pub mod async_drop;
+
+#[cfg(feature = "codegen")]
+pub mod codegen;
+
+#[cfg(feature = "test-util")]
+pub mod diff;
+
+#[cfg(feature = "visit")]
+pub mod visit;
@@ -0,0 +1,150 @@
+//! Walking the fields of pinned objects for diagnostics.
+//!
+//! This module is only available with the `visit` feature.
+
+use core::pin::Pin;
+
+/// Description of a visited field.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldInfo {
+    /// Name of the member.
+    pub name: &'static str,
+    /// Name of the type of the member, as returned by `core::any::type_name()`.
+    pub type_name: &'static str,
+    /// Address of the member.
+    pub address: *const (),
+    /// Size of the member in bytes.
+    pub size: usize,
+}
+
+/// Receives the fields of objects walked by [`PinVisit::pin_visit()`].
+pub trait PinVisitor {
+    /// Called for every field that is not recursed into.
+    fn field(&mut self, field: &FieldInfo);
+
+    /// Called before recursing into a child. Defaults to calling [`PinVisitor::field()`].
+    fn enter(&mut self, field: &FieldInfo) {
+        self.field(field);
+    }
+
+    /// Called after all fields of a child were visited.
+    fn leave(&mut self, _field: &FieldInfo) {}
+}
+
+/// Types that can walk their fields from a pinned reference.
+///
+/// Usually implemented with the [`pin_visit!`] macro.
+///
+/// [`pin_visit!`]: crate::pin_visit
+pub trait PinVisit {
+    /// Calls the visitor for each declared field of `self`.
+    fn pin_visit(self: Pin<&Self>, visitor: &mut dyn PinVisitor);
+}
+
+/// Implements [`PinVisit`] for a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// pin_visit!($TYPE { [visit] $MEMBER, ... })
+/// ```
+///
+/// Fields are visited in the order given. Members prefixed with `visit` must implement
+/// [`PinVisit`] themselves and are recursed into, the visitor gets [`PinVisitor::enter()`]
+/// and [`PinVisitor::leave()`] calls around their fields. All other members are reported by
+/// [`PinVisitor::field()`].
+///
+/// # SAFETY
+///
+/// Recursing creates a `Pin<&Child>` for each `visit` member, these must be structurally
+/// pinned.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::pin_visit;
+/// use pin_projections::visit::{FieldInfo, PinVisitor, PinVisit};
+/// use std::pin::Pin;
+///
+/// struct Timer {
+///     deadline: u64,
+/// }
+/// pin_visit!(Timer { deadline });
+///
+/// struct Task {
+///     id: u32,
+///     timer: Timer,
+/// }
+/// pin_visit!(Task { id, visit timer });
+///
+/// #[derive(Default)]
+/// struct Dump {
+///     depth: usize,
+///     lines: Vec<String>,
+/// }
+///
+/// impl PinVisitor for Dump {
+///     fn field(&mut self, field: &FieldInfo) {
+///         self.lines.push(format!("{}{}", "  ".repeat(self.depth), field.name));
+///     }
+///     fn enter(&mut self, field: &FieldInfo) {
+///         self.field(field);
+///         self.depth += 1;
+///     }
+///     fn leave(&mut self, _field: &FieldInfo) {
+///         self.depth -= 1;
+///     }
+/// }
+///
+/// let task = Box::pin(Task { id: 1, timer: Timer { deadline: 100 } });
+/// let mut dump = Dump::default();
+/// task.as_ref().pin_visit(&mut dump);
+/// assert_eq!(dump.lines, ["id", "timer", "  deadline"]);
+/// ```
+#[macro_export]
+macro_rules! pin_visit {
+    (@field $this:ident $visitor:ident visit $M:ident) => {
+        let info = $crate::pin_visit!(@info $this $M);
+        $visitor.enter(&info);
+        $crate::visit::PinVisit::pin_visit(
+            unsafe { ::core::pin::Pin::map_unchecked($this, |s| &s.$M) },
+            $visitor,
+        );
+        $visitor.leave(&info);
+    };
+    (@field $this:ident $visitor:ident $M:ident) => {
+        $visitor.field(&$crate::pin_visit!(@info $this $M));
+    };
+    (@info $this:ident $M:ident) => {
+        $crate::visit::FieldInfo {
+            name: ::core::stringify!($M),
+            type_name: $crate::visit::__type_name_of(&$this.$M),
+            address: ::core::ptr::addr_of!($this.$M) as *const (),
+            size: ::core::mem::size_of_val(&$this.$M),
+        }
+    };
+    (@fields $this:ident $visitor:ident) => {};
+    (@fields $this:ident $visitor:ident visit $M:ident $(, $($rest:tt)*)?) => {
+        $crate::pin_visit!(@field $this $visitor visit $M);
+        $crate::pin_visit!(@fields $this $visitor $($($rest)*)?);
+    };
+    (@fields $this:ident $visitor:ident $M:ident $(, $($rest:tt)*)?) => {
+        $crate::pin_visit!(@field $this $visitor $M);
+        $crate::pin_visit!(@fields $this $visitor $($($rest)*)?);
+    };
+    ($T:ty { $($fields:tt)* }) => {
+        impl $crate::visit::PinVisit for $T {
+            fn pin_visit(
+                self: ::core::pin::Pin<&Self>,
+                visitor: &mut dyn $crate::visit::PinVisitor,
+            ) {
+                $crate::pin_visit!(@fields self visitor $($fields)*);
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub fn __type_name_of<T: ?Sized>(_: &T) -> &'static str {
+    core::any::type_name::<T>()
+}
@@ -0,0 +1,149 @@
+//! Members that are structurally pinned by construction.
+
+use core::fmt;
+use core::pin::Pin;
+
+/// A member whose content can only be accessed pinned.
+///
+/// There is no way to get a `&mut T` out of a `PinnedField<T>`, the content is only handed out
+/// as `Pin<&T>` or `Pin<&mut T>`. Together with [`pinned_fields!`](crate::pinned_fields) on
+/// the containing struct the `pinned` forms of [`project!`](crate::project) project to the
+/// content without `unsafe` in the user's code. The member itself must not be handed out as
+/// `&mut PinnedField<T>`, e.g. by a `-> &mut PinnedField<T>` projection, the content could
+/// then be moved with `mem::swap`.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::field::PinnedField;
+/// use pin_projections::{pinned_fields, project};
+/// use std::pin::Pin;
+///
+/// struct Timer {
+///     deadline: u64,
+/// }
+///
+/// struct Task {
+///     timer: PinnedField<Timer>,
+///     id: u32,
+/// }
+/// pinned_fields!(Task);
+///
+/// impl Task {
+///     project!(timer -> pinned Pin<&Timer>);
+///     project!(timer as timer_mut() -> pinned Pin<&mut Timer>);
+/// }
+///
+/// let mut task = Box::pin(Task { timer: PinnedField::new(Timer { deadline: 100 }), id: 1 });
+/// let _: Pin<&mut Timer> = task.as_mut().timer_mut();
+/// assert_eq!(task.as_ref().timer().deadline, 100);
+/// ```
+///
+/// Projections fail to compile when the struct misses the
+/// [`pinned_fields!`](crate::pinned_fields) guards:
+///
+/// ```compile_fail
+/// # use pin_projections::field::PinnedField;
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Task {
+///     timer: PinnedField<u64>,
+/// }
+///
+/// impl Task {
+///     project!(timer -> pinned Pin<&u64>);
+/// }
+/// ```
+#[derive(Default)]
+pub struct PinnedField<T: ?Sized> {
+    value: T,
+}
+
+impl<T> PinnedField<T> {
+    /// Wraps a not yet pinned value.
+    pub const fn new(value: T) -> Self {
+        PinnedField { value }
+    }
+
+    /// Returns the content, the field is not pinned when it is owned.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> PinnedField<T> {
+    /// Projects to the pinned content.
+    pub fn get(self: Pin<&Self>) -> Pin<&T> {
+        unsafe { Pin::map_unchecked(self, |s| &s.value) }
+    }
+
+    /// Projects to the mutable pinned content.
+    pub fn get_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        unsafe { Pin::map_unchecked_mut(self, |s| &mut s.value) }
+    }
+}
+
+impl<T> From<T> for PinnedField<T> {
+    fn from(value: T) -> Self {
+        PinnedField::new(value)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for PinnedField<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PinnedField").field(&&self.value).finish()
+    }
+}
+
+/// Structs that can't move their [`PinnedField`] members once pinned.
+///
+/// # SAFETY
+///
+/// Implementors must not implement `Drop` and must not be `Unpin`. Only implement it with
+/// [`pinned_fields!`](crate::pinned_fields) which checks both.
+pub unsafe trait PinnedFields {}
+
+/// Implements [`PinnedFields`] for a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// pinned_fields!($TYPE)
+/// ```
+///
+/// Written next to the struct definition. The struct becomes `!Unpin` and it is a compile
+/// error when it implements `Drop` or `Unpin`, these would allow moving the content of a
+/// pinned [`PinnedField`] in safe code. `#[repr(packed)]` structs are rejected by the
+/// projections themselves. The type must not depend on generic parameters.
+///
+/// ```compile_fail
+/// # use pin_projections::field::PinnedField;
+/// # use pin_projections::pinned_fields;
+/// struct Task {
+///     timer: PinnedField<u64>,
+/// }
+/// pinned_fields!(Task);
+///
+/// impl Drop for Task {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+#[macro_export]
+macro_rules! pinned_fields {
+    ($T:ty) => {
+        const _: () = {
+            trait MustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> MustNotImplDrop for T {}
+            impl MustNotImplDrop for $T {}
+
+            pub struct AlwaysPinned<'a>(
+                ::core::marker::PhantomData<&'a ()>,
+                ::core::marker::PhantomPinned,
+            );
+            impl<'a> ::core::marker::Unpin for $T where AlwaysPinned<'a>: ::core::marker::Unpin {}
+
+            unsafe impl $crate::field::PinnedFields for $T {}
+        };
+    };
+}
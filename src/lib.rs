@@ -2,6 +2,261 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+pub mod access;
+mod array_element;
+mod async_lock;
+pub mod atomic;
+mod boxes;
+mod callback;
+pub mod check;
+mod checkpoint;
+mod compat;
+mod complete;
+mod doc;
+mod doc_examples;
+mod each;
+pub mod field;
+mod marker;
+mod parking_lot;
+mod pin_struct;
+pub mod pinned_drop;
+pub mod poison;
+mod policy;
+mod raw;
+mod report;
+mod stack;
+mod tagged;
+mod trace;
+mod union_field;
+mod waker;
+
+mod extras;
+
+#[cfg(feature = "abi")]
+pub use extras::abi;
+
+#[cfg(feature = "async-drop")]
+pub use extras::async_drop;
+
+#[cfg(feature = "codegen")]
+pub use extras::codegen;
+
+#[cfg(feature = "test-util")]
+pub use extras::diff;
+
+#[cfg(feature = "visit")]
+pub use extras::visit;
+
+/// Derives pin projections from member attributes.
+///
+/// This is an alternative to writing the `project!` invocations by hand, it generates an
+/// `impl` block with the same projections the declarative macro would. Only available with
+/// the `derive` feature.
+///
+/// Members are marked with:
+///  - `#[pin]`: structurally pinned, generates `fn member(self: Pin<&Self>) -> Pin<&T>`.
+///  - `#[pin(mut)]`: structurally pinned, additionally generates
+///    `fn member_mut(self: Pin<&mut Self>) -> Pin<&mut T>`.
+///  - `#[unpin]`: not structurally pinned, generates `fn member(self: Pin<&Self>) -> &T` and
+///    `fn member_mut(self: Pin<&mut Self>) -> &mut T`.
+///
+/// For raw identifiers like `r#type` the `_mut` projection drops the `r#`, it is named
+/// `type_mut`.
+///
+/// Members without these attributes get no projections. The generated functions have the
+/// same visibility as the member. The last member may be unsized, like `[T]`, `dyn Trait` or
+/// a `T: ?Sized` generic.
+///
+/// When members are structurally pinned the derive checks the pinning rules at compile time:
+///  - The struct is `Unpin` only when all members without `#[unpin]` are. A manual `Unpin`
+///    implementation conflicts with the generated one.
+///  - The struct must not implement `Drop`, except through [`pinned_drop!`] which is declared
+///    with `#[pin(PinnedDrop)]` on the struct.
+///  - `#[repr(packed)]` is rejected.
+///
+/// ```compile_fail
+/// use pin_projections::PinProjections;
+/// use std::marker::PhantomPinned;
+///
+/// #[derive(PinProjections)]
+/// struct Task {
+///     #[pin]
+///     timer: PhantomPinned,
+/// }
+///
+/// impl Drop for Task {
+///     fn drop(&mut self) {}
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use pin_projections::PinProjections;
+/// use std::marker::PhantomPinned;
+///
+/// #[derive(PinProjections)]
+/// struct Task {
+///     #[pin]
+///     timer: PhantomPinned,
+/// }
+///
+/// impl Unpin for Task {}
+/// ```
+///
+/// ```
+/// use pin_projections::{pinned_drop, PinProjections};
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+///
+/// #[derive(PinProjections)]
+/// #[pin(PinnedDrop)]
+/// struct Task {
+///     #[pin]
+///     timer: PhantomPinned,
+/// }
+///
+/// pinned_drop! {
+///     impl PinnedDrop for Task {
+///         fn drop_pinned(self: Pin<&mut Self>) {
+///             let _timer: Pin<&PhantomPinned> = self.as_ref().timer();
+///         }
+///     }
+/// }
+/// # drop(Box::pin(Task { timer: PhantomPinned }));
+/// ```
+///
+/// Deriving for a `#[repr(packed)]` struct is a compile error:
+///
+/// ```compile_fail
+/// use pin_projections::PinProjections;
+///
+/// #[derive(PinProjections)]
+/// #[repr(C, packed)]
+/// struct Packed {
+///     #[pin]
+///     inner: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use pin_projections::PinProjections;
+///
+/// #[derive(PinProjections)]
+/// #[repr(packed(2))]
+/// struct Packed {
+///     #[unpin]
+///     inner: u8,
+/// }
+/// ```
+///
+/// The struct may have lifetimes with bounds, type and const generics with defaults and a
+/// `where` clause. Member types are taken verbatim, they may contain `->` or commas:
+///
+/// ```
+/// use pin_projections::PinProjections;
+/// use std::fmt::Debug;
+/// use std::pin::Pin;
+///
+/// #[derive(PinProjections)]
+/// struct Parser<'a, 'b: 'a, T: Debug = Vec<u8>, const N: usize = 4>
+/// where
+///     T: Clone,
+/// {
+///     #[pin]
+///     input: &'a T,
+///     #[unpin]
+///     r#type: [u8; N],
+///     #[pin(mut)]
+///     callback: fn(&'b str) -> usize,
+///     #[unpin]
+///     map: Box<dyn Fn(u8, u8) -> Option<u8>>,
+/// }
+///
+/// let data = vec![1, 2];
+/// let mut parser: Pin<Box<Parser<'_, '_>>> = Box::pin(Parser {
+///     input: &data,
+///     r#type: [0; 4],
+///     callback: str::len,
+///     map: Box::new(u8::checked_add),
+/// });
+/// *parser.as_mut().type_mut() = [1; 4];
+/// assert_eq!(parser.as_ref().r#type(), &[1; 4]);
+/// assert_eq!(parser.as_ref().input().len(), 2);
+/// assert_eq!((*parser.as_mut().callback_mut())("abc"), 3);
+/// assert_eq!((parser.as_ref().map())(1, 2), Some(3));
+/// ```
+///
+/// A member takes at most one of the attributes:
+///
+/// ```compile_fail
+/// use pin_projections::PinProjections;
+///
+/// #[derive(PinProjections)]
+/// struct Task {
+///     #[pin]
+///     #[unpin]
+///     timer: u64,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use pin_projections::PinProjections;
+///
+/// #[derive(PinProjections)]
+/// struct Task {
+///     #[pin]
+///     #[pin(mut)]
+///     timer: u64,
+/// }
+/// ```
+///
+/// # SAFETY
+///
+/// The same rules as for [`project!`] apply.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::PinProjections;
+/// use std::pin::Pin;
+///
+/// #[derive(PinProjections)]
+/// struct Example<T> {
+///     #[pin(mut)]
+///     inner: T,
+///     #[unpin]
+///     counter: u32,
+///     untouched: bool,
+/// }
+///
+/// let mut example = Box::pin(Example { inner: 42, counter: 0, untouched: true });
+/// *example.as_mut().counter_mut() += 1;
+/// let inner: Pin<&mut i32> = example.as_mut().inner_mut();
+/// assert_eq!(*inner, 42);
+/// assert_eq!(*example.as_ref().counter(), 1);
+/// ```
+#[cfg(feature = "derive")]
+pub use pin_projections_derive::PinProjections;
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
+
+#[cfg(feature = "report")]
+#[doc(hidden)]
+pub use report::marker as __report_marker;
+
+#[doc(hidden)]
+pub use union_field::UnionField as __UnionField;
+
+#[doc(hidden)]
+pub use array_element::ArrayElement as __ArrayElement;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+
+#[cfg(feature = "alloc")]
+mod construct;
+
 /// Defines a pin projection.
 ///
 /// Projections are written inside the `impl` block of a struct.
@@ -11,7 +266,142 @@
 /// ```text
 /// project!([pub] [unsafe] $MEMBER as $FUNCTION() -> $PROJECTION)
 /// project!([pub] [unsafe] $MEMBER -> $PROJECTION)
+/// project!([pub] [unsafe] $MEMBER as $FUNCTION<$LIFETIME>() -> $PROJECTION)
+/// project!([pub] [unsafe] $MEMBER as $FUNCTION(), $FUNCTION_MUT() -> $PAIR)
+/// project!([pub] [unsafe] $MEMBER, $FUNCTION_MUT() -> $PAIR)
+/// project!([pub] [unsafe] $MEMBER as $FUNCTION[..] -> $SLICE)
+/// project!([pub] unsafe $MEMBER |$BINDING| $MAPPING as $FUNCTION() -> $PROJECTION)
+/// project!([pub] [unsafe] $MEMBER as $FUNCTION<const $INDEX>() -> $PROJECTION)
+/// project!([pub] [unsafe] $MEMBER as $FUNCTION<$TYPE>() -> downcast $OPTION)
 /// project!([pub] [unsafe] $MEMBER as $FUNCTION($FROM))
+/// project!([pub] unsafe $MEMBER as $FUNCTION($FROM) -> uninit Pin<&mut Type>)
+/// project!([pub] [unsafe] $MEMBER as $FUNCTION() -> $OPTION if $CONDITION)
+/// project!([pub] not_pinned $MEMBER as $FUNCTION() -> $REFERENCE)
+/// project!([pub] not_pinned $MEMBER as $FUNCTION<$LIFETIME>() -> $REFERENCE)
+/// project!([pub] not_pinned $MEMBER -> $REFERENCE)
+/// project!([pub] not_pinned $MEMBER: Type)
+/// project!([pub] not_pinned $MEMBER, $FUNCTION_MUT() -> &(mut) Type)
+/// project!([pub] not_pinned $MEMBER as $FUNCTION(Pin<&mut Self>))
+/// project!([pub] self as $FUNCTION() -> Pin<&mut Self>)
+/// project!([pub] self as $FUNCTION() -> Pin<&Self>)
+/// project!([pub] [unsafe] box $MEMBER as $FUNCTION() -> $PROJECTION)
+/// project!([pub] [unsafe] box $MEMBER as $FUNCTION($FROM))
+/// project!([pub] unsafe box $MEMBER as $FUNCTION() -> into Type)
+/// project!([pub] [unsafe] rc $MEMBER as $FUNCTION() -> $PROJECTION)
+/// project!([pub] [unsafe] arc $MEMBER as $FUNCTION() -> $PROJECTION)
+/// project!([pub] unsafe promote $MEMBER as $FUNCTION() -> $PROJECTION)
+/// project!([pub] unsafe drop $MEMBER as $FUNCTION())
+/// project!([pub] safe $MEMBER as $FUNCTION() -> $PROJECTION)
+/// project!([pub] safe $MEMBER -> $PROJECTION)
+/// project!([pub] safe box $MEMBER as $FUNCTION() -> into Type)
+/// project!([pub] atomic_waker $MEMBER as $FUNCTION(&Waker))
+/// project!([pub] atomic_waker $MEMBER as $FUNCTION())
+/// ```
+///
+/// All forms can be followed by a `where` clause for the generated function and be preceded
+/// by `#[stable($NAME)]`, see [stable entry points](#stable-entry-points), by an inlining
+/// attribute, see [inlining](#inlining), and by doc comments, see
+/// [documentation](#documentation). An invocation can start with `policy = $MACRO;`, see
+/// [policies](#policies), and with `example = $TYPE;`, see [usage examples](#usage-examples).
+/// With the `tracing` feature the visibility can be followed by `traced`, the generated
+/// function then emits a `tracing::trace!` event with the member, the function, the type of
+/// `Self` and the caller on every call, e.g. `project!(pub traced state -> &mut State)`.
+///
+/// Multiple declarations can be given in one invocation, separated by `;`. Declarations can be
+/// grouped as `feature "name" { ... }`, the group is only emitted when the cargo feature
+/// `name` of the invoking crate is enabled:
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Example {
+///     pinned: u32,
+///     counters: u64,
+/// }
+///
+/// impl Example {
+///     project! {
+///         pinned -> Pin<&u32>;
+///         pinned as pinned_mut() -> Pin<&mut u32>;
+///         feature "metrics" {
+///             counters -> &u64;
+///         }
+///     }
+/// }
+/// ```
+///
+/// A struct with many members can declare all of its projections in one invocation:
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Registers {
+///     r0: u32, r1: u32, r2: u32, r3: u32, r4: u32, r5: u32, r6: u32, r7: u32,
+///     r8: u32, r9: u32, r10: u32, r11: u32, r12: u32, r13: u32, r14: u32,
+/// }
+///
+/// impl Registers {
+///     project! {
+///         pub r0 -> Pin<&mut u32>; pub r0 as r0_ref() -> &u32;
+///         pub r1 -> Pin<&mut u32>; pub r1 as r1_ref() -> &u32;
+///         pub r2 -> Pin<&mut u32>; pub r2 as r2_ref() -> &u32;
+///         pub r3 -> Pin<&mut u32>; pub r3 as r3_ref() -> &u32;
+///         pub r4 -> Pin<&mut u32>; pub r4 as r4_ref() -> &u32;
+///         pub r5 -> Pin<&mut u32>; pub r5 as r5_ref() -> &u32;
+///         pub r6 -> Pin<&mut u32>; pub r6 as r6_ref() -> &u32;
+///         pub r7 -> Pin<&mut u32>; pub r7 as r7_ref() -> &u32;
+///         pub r8 -> Pin<&mut u32>; pub r8 as r8_ref() -> &u32;
+///         pub r9 -> Pin<&mut u32>; pub r9 as r9_ref() -> &u32;
+///         pub r10 -> Pin<&mut u32>; pub r10 as r10_ref() -> &u32;
+///         pub r11 -> Pin<&mut u32>; pub r11 as r11_ref() -> &u32;
+///         pub r12 -> Pin<&mut u32>; pub r12 as r12_ref() -> &u32;
+///         pub r13 -> Pin<&mut u32>; pub r13 as r13_ref() -> &u32;
+///         pub r14 -> Pin<&mut u32> where Self: Sized; pub r14 as r14_ref() -> &u32;
+///     }
+/// }
+///
+/// let mut registers = Box::pin(Registers {
+///     r0: 0, r1: 1, r2: 2, r3: 3, r4: 4, r5: 5, r6: 6, r7: 7,
+///     r8: 8, r9: 9, r10: 10, r11: 11, r12: 12, r13: 13, r14: 14,
+/// });
+/// *registers.as_mut().r14() += 1;
+/// assert_eq!(*registers.as_ref().r14_ref(), 15);
+/// ```
+///
+/// Each declaration of a batch takes one or two levels of the recursion limit, about 60
+/// declarations fit into the default limit of 128. Larger batches need a higher
+/// `#![recursion_limit]` in the crate using them or are split into several invocations:
+///
+/// ```
+/// #![recursion_limit = "512"]
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// macro_rules! registers {
+///     ($($R:ident)*) => {
+///         struct Registers {
+///             $($R: u32,)*
+///         }
+///
+///         impl Registers {
+///             project! {
+///                 $(pub $R -> Pin<&mut u32>;)*
+///             }
+///         }
+///     };
+/// }
+///
+/// registers!(
+///     r0 r1 r2 r3 r4 r5 r6 r7 r8 r9 r10 r11 r12 r13
+///     r14 r15 r16 r17 r18 r19 r20 r21 r22 r23 r24 r25 r26 r27
+///     r28 r29 r30 r31 r32 r33 r34 r35 r36 r37 r38 r39 r40 r41
+///     r42 r43 r44 r45 r46 r47 r48 r49 r50 r51 r52 r53 r54 r55
+///     r56 r57 r58 r59 r60 r61 r62 r63 r64 r65 r66 r67 r68 r69
+///     r70 r71 r72 r73 r74 r75 r76 r77 r78 r79 r80 r81 r82 r83
+///     r84 r85 r86 r87 r88 r89 r90 r91 r92 r93 r94 r95 r96 r97
+///     r98 r99 r100 r101 r102 r103 r104 r105 r106 r107 r108 r109 r110 r111
+///     r112 r113 r114 r115 r116 r117 r118 r119 r120 r121 r122 r123 r124 r125
+///     r126 r127 r128 r129 r130 r131 r132 r133 r134 r135 r136 r137 r138 r139
+/// );
 /// ```
 ///
 /// The parameters are:
@@ -19,24 +409,1218 @@
 ///  - **unsafe** is optional and generates an unsafe projection function.
 ///  - **MEMBER:** name of the structures member to project
 ///  - **FUNCTION:** name for the projection function (optional, when not given the MEMBER name is used)
+///    MEMBER can be a raw identifier like `r#type`. Without FUNCTION the projection is named
+///    `r#type` as well, since `macro_rules!` can't strip the prefix. Give a FUNCTION for a
+///    name that is no keyword.
 ///  - **PROJECTION:** resulting type
 ///    Type of MEMBER as:
 ///    - `Pin<&Type>`
-///    - `Pin<&mut Type>`
+///    - `Pin<&mut Type>`, see [`check`] for debug checks that the object doesn't move.
+///    - `Pin<&[Type]>` or `Pin<&mut [Type]>` when MEMBER is a `Vec<Type>`, an array or a
+///      slice of `Type`, the elements are structurally pinned. A `Vec` must then never be
+///      resized or reallocated while the object is pinned.
 ///    - `&Type`
 ///    - `&mut Type`
-///    - `Type`
+///    - `Type`, a keyword followed by `::` starts a path, `cell::Type` is this form and not
+///      `cell Type` below.
+///    - `|Pin<&Type>|`, `|Pin<&mut Type>|`, `|&Type|` or `|&mut Type|` for a scoped projection
+///      that takes a closure `f: impl FnOnce(Projection) -> R`, calls it with the projection
+///      and returns its result `R`.
+///    - `poisoned Pin<&Type>`, `poisoned Pin<&mut Type>` or `poisoned |Pin<&mut Type>|` when
+///      MEMBER is a [`PinPoison<Type>`](poison::PinPoison). These return
+///      `Result<_, Poisoned>`, the scoped form poisons the member when the closure panics.
+///    - `pinned Pin<&Type>` or `pinned Pin<&mut Type>` when MEMBER is a
+///      [`PinnedField<Type>`](field::PinnedField) and the struct is guarded by
+///      [`pinned_fields!`](crate::pinned_fields), it is a compile error when the guard is
+///      missing. The content stays pinned as long as no other projection hands out the
+///      member unpinned, e.g. `-> &mut PinnedField<Type>` allows swapping it.
+///    - `union Pin<&Type>`, `union Pin<&mut Type>`, `union &Type` or `union &mut Type` when
+///      MEMBER is a member of a union, either a `ManuallyDrop<Type>` or a `Copy` type. These
+///      must be declared `unsafe`, the caller must ensure that MEMBER is the active one.
+///    - `cell *mut Type` or `cell Pin<&mut Type>` when MEMBER is an `UnsafeCell<Type>`. Both
+///      take `self: Pin<&Self>`, the second must be declared `unsafe` as the caller has to
+///      guarantee exclusive access. `&UnsafeCell<Type>` is the plain reference form above.
+///    - `expect Pin<&Type>` or `expect Pin<&mut Type>` when MEMBER is an `Option<Type>` that
+///      is `Some` by an invariant of the struct. The content is structurally pinned, the
+///      generated function is `#[track_caller]` and panics with the member name when MEMBER
+///      is `None`.
+///    - `get_or_insert_with Pin<&mut Type>` when MEMBER is an `Option<Type>`, generates a
+///      function taking `init: impl FnOnce() -> Type` that initializes MEMBER in place with
+///      the result of `init` when it is `None`. The content is structurally pinned.
+///    - `cell Type` when MEMBER is a `Cell<Type>` and `Type` is `Copy`, returns a copy of the
+///      content. Takes `self: Pin<&Self>`.
+///    - `atomic Type` when MEMBER is an atomic `Type` like `AtomicUsize`, returns an
+///      [`AtomicOps`](atomic::AtomicOps) with `load`, `store`, `swap` and `compare_exchange`
+///      taking an `Ordering`. Takes `self: Pin<&Self>`.
+///    - `uninit &mut Type` or `uninit Pin<&mut Type>` when MEMBER is a `MaybeUninit<Type>`.
+///      The first returns `&mut MaybeUninit<Type>`, the second assumes that MEMBER is
+///      initialized. These must be declared `unsafe`.
+///    - `boxed Option<Pin<&Type>>` or `boxed Option<Pin<&mut Type>>` when MEMBER is an
+///      `Option<Box<Type>>`, the content of the box is structurally pinned. Only available with
+///      the `alloc` feature.
+///    - `shared &Type` or `shared Type` when MEMBER is an `Rc<Type>`, `Arc<Type>` or another
+///      smart pointer dereferencing to `Type`, `shared Pin<&Type>` when MEMBER is a
+///      `Pin<Rc<Type>>` or `Pin<Arc<Type>>`. These take `self: Pin<&Self>` and return the
+///      pointee by reference, cloned or pinned. Only pointers that are pinned already can hand
+///      out their pointee pinned, other clones of an unpinned `Rc` could still move it.
+///    - `Result<Pin<&Type>, Pin<&Error>>` or `Result<Pin<&mut Type>, Pin<&mut Error>>` when
+///      MEMBER is a `Result<Type, Error>`, both variants are structurally pinned.
+///    - `deref &Type` or `deref &mut Type` when MEMBER dereferences to `Type`, like `&str`
+///      from a `String` or `&[u8]` from a `Vec<u8>`. The mutable form is for members that
+///      are not structurally pinned.
+///    - `pin Pin<&Type>` or `pin Pin<&mut Type>` when MEMBER is already pinned, a `Pin<Ptr>`
+///      with `Ptr` dereferencing to `Type` like `Pin<Box<Type>>` or `Pin<&'static mut Type>`.
+///      These reborrow the pin with `as_ref()` or `as_mut()` and have no safety requirements,
+///      MEMBER itself is not structurally pinned. The mutable form requires `Self: Unpin`.
+///    - `manually_drop Pin<&Type>`, `manually_drop Pin<&mut Type>`, `manually_drop &Type` or
+///      `manually_drop &mut Type` when MEMBER is a `ManuallyDrop<Type>`, these project through
+///      the wrapper. See **drop** below for dropping MEMBER.
+///    - `bytes Pin<&Type>` returns `(Pin<&Type>, &[u8])`, MEMBER together with its bytes.
+///      `bytes &mut [u8]` returns the bytes of MEMBER for writing. These must be declared
+///      `unsafe`, the caller must ensure that MEMBER is `#[repr(C)]` without padding and
+///      that any byte pattern written is a valid `Type`. A mutable typed reference and a byte
+///      view of the same member can't exist at the same time, get them one after another.
+///    - `std MutexGuard<Type>` when MEMBER is a `std::sync::Mutex<Type>`,
+///      `std RwLockReadGuard<Type>` or `std RwLockWriteGuard<Type>` when it is a
+///      `std::sync::RwLock<Type>`. These take `self: Pin<&Self>`, lock MEMBER and return a
+///      `LockResult` of the guard, poisoning is left to the caller. The generated code refers
+///      to `std`.
+///    - `parking_lot MutexGuard<Type>`, `parking_lot RwLockReadGuard<Type>` or
+///      `parking_lot RwLockWriteGuard<Type>` when MEMBER is a `parking_lot::Mutex<Type>` or
+///      `parking_lot::RwLock<Type>`, these take `self: Pin<&Self>` and lock MEMBER. Wrapped in
+///      `Option`, like `parking_lot Option<MutexGuard<Type>>`, they try to lock MEMBER and
+///      return `None` when it is locked already. Only available with the `parking_lot`
+///      feature.
+///    - `poll Type` when MEMBER is a `Future<Output = Type>`, generates a function taking
+///      `self: Pin<&mut Self>` and `cx: &mut Context<'_>` that polls MEMBER and returns
+///      `Poll<Type>`. This is for custom `poll()` implementations that poll several inner
+///      futures. A plain `Poll<Type>` is the cloning getter of a `Poll<Type>` member.
+///    - `async MutexGuard<Type>` when MEMBER is a `tokio::sync::Mutex<Type>`, generates an
+///      `async fn` taking `self: Pin<&Self>` that locks MEMBER. Only available with the
+///      `tokio` feature.
+///  - **BINDING** and **MAPPING:** a custom projection, MAPPING is an expression that maps
+///    BINDING, a reference to MEMBER, to a reference to the projected place. PROJECTION is
+///    one of the `Pin<&Type>`, `Pin<&mut Type>`, `&Type` or `&mut Type` forms, BINDING is a
+///    `&mut` reference for the mutable ones. This covers projections through newtypes or
+///    nested members. It must be declared `unsafe`, the caller must ensure that the mapping
+///    upholds the pinning guarantees. A top level `as` in MAPPING must be parenthesized.
+///  - **PAIR:** `Pin<&(mut) Type>` or `&(mut) Type` generates two projections, FUNCTION
+///    returning `Pin<&Type>` or `&Type` and FUNCTION_MUT returning `Pin<&mut Type>` or
+///    `&mut Type`. Without FUNCTION the shared projection is named like MEMBER. The
+///    `not_pinned` forms take `&(mut) Type` as well.
+///  - **LIFETIME:** names the lifetime of the `self` borrow. The PROJECTION must then be one
+///    of the reference types above with an explicit lifetime like `Pin<&'s mut Type>`, this is
+///    needed when the elided lifetimes don't fit.
+///  - **SLICE:** `Pin<&[Type]>`, `Pin<&mut [Type]>`, `&[Type]` or `&mut [Type]` for members
+///    that can be indexed by a range, like arrays, slices or vectors of `Type`. The generated
+///    function takes a `range: Range<usize>` and returns the sub-slice. It panics when the
+///    range is out of bounds, just like slice indexing.
+///  - **INDEX:** names a `const` generic parameter of FUNCTION, MEMBER must be an array
+///    `[Type; N]`. The projection to element INDEX is one of the `Pin<&Type>`,
+///    `Pin<&mut Type>`, `&Type` or `&mut Type` forms. An INDEX out of bounds is a compile
+///    error.
+///  - **TYPE** and **downcast OPTION:** names a type parameter of FUNCTION bounded on `Any`,
+///    MEMBER must be a `Box<dyn Any>` or another pointer to a `dyn Any`, possibly with
+///    `+ Send` or `+ Sync`. OPTION is `Option<Pin<&TYPE>>` or `Option<Pin<&mut TYPE>>`, the
+///    projection returns the content of MEMBER pinned when it is a TYPE and `None` otherwise.
+///    The content of MEMBER is structurally pinned.
+///  - **OPTION if CONDITION:** `Option<Pin<&Type>>` or `Option<Pin<&mut Type>>` for a guarded
+///    projection. CONDITION is a boolean expression on `self`, the projection returns `None`
+///    when it is false.
 ///  - **FROM:** source for setters must by the type of MEMBER
 ///    - `Type` for a owned setter.
 ///    - `&Type` for a cloning setter.
+///    - `cell Type` for a setter of a `Cell<Type>` MEMBER, it takes `self: Pin<&Self>`.
+///    - `Type` with `-> uninit Pin<&mut Type>` for an initializing setter of a
+///      `MaybeUninit<Type>` MEMBER. It writes FROM without dropping the old value and returns
+///      the pinned initialized MEMBER. It must be declared `unsafe`.
+///  - **not_pinned** declares that MEMBER is not structurally pinned. REFERENCE is `&Type`,
+///    `&mut Type` or `|&mut Type|` for an update in place by a closure, the short form
+///    generates `&mut Type`. It is a compile error when `Type` is not `Unpin`.
+///    `(Pin<&mut Self>)` generates a function swapping MEMBER of `self` with MEMBER of
+///    `other: Pin<&mut Self>`, the type of MEMBER must be `Unpin` as well.
+///  - **self** instead of a MEMBER generates a reborrow of `self: &mut Pin<&mut Self>` as
+///    `Pin<&mut Self>` or a downgrade of `self: &Pin<&mut Self>` to `Pin<&Self>`. These
+///    allow calling several projections in a row on the same `Pin<&mut Self>`.
+///  - **box** generates a projection that takes `self: &Pin<Box<Self>>` or
+///    `self: &mut Pin<Box<Self>>` instead of `Pin<&Self>` or `Pin<&mut Self>`, it can be
+///    called on a pinned box without `as_ref()` or `as_mut()`. PROJECTION is one of the
+///    `Pin<&Type>`, `Pin<&mut Type>`, `&Type`, `&mut Type` or `Type` forms. The FUNCTION name
+///    is mandatory as it must differ from the `Pin<&mut Self>` projection. Only available
+///    with the `alloc` feature.
+///    `into Type` generates a function taking `self: Pin<Box<Self>>` that moves MEMBER out
+///    and drops the other members in place. It must be declared `unsafe`, the caller must
+///    ensure that nothing relies on MEMBER staying pinned, e.g. that it is `Unpin` or not
+///    structurally pinned. `Self` must not implement `Drop`.
+///  - **rc** and **arc** generate a projection that takes `self: &Pin<Rc<Self>>` or
+///    `self: &Pin<Arc<Self>>`, for objects that are shared by reference counting. PROJECTION
+///    is one of the shared `Pin<&Type>`, `&Type` or `Type` forms, the FUNCTION name is
+///    mandatory. Only available with the `alloc` feature.
+///  - **safe** generates a projection without any `unsafe` code. PROJECTION is one of the
+///    `Pin<&Type>`, `Pin<&mut Type>`, `&Type` or `&mut Type` forms, the generated function is
+///    bounded on `Type: Unpin` for the pinned and on `Self: Unpin` for the mutable ones.
+///    `into Type` generates a function consuming `self` and returning MEMBER, with **box** it
+///    consumes a `Pin<Box<Self>>` instead. Both are bounded on `Self: Unpin`, `Self` must not
+///    implement `Drop`.
+///  - **drop** generates a function taking `self: Pin<&mut Self>` that drops MEMBER, a
+///    `ManuallyDrop<Type>`, in place. It must be declared `unsafe`, the caller must ensure
+///    that MEMBER is not used afterwards and not dropped again.
+///  - **promote** generates a projection that takes `self: &Self` or `self: &mut Self` and
+///    returns MEMBER as `Pin<&Type>` or `Pin<&mut Type>`. This is for containers that are
+///    owned unpinned but guarantee that MEMBER never moves, like nodes of intrusive
+///    collections. It must be declared `unsafe`, the caller must ensure that MEMBER is never
+///    moved again until it is dropped, even when `self` is moved.
+///  - **atomic_waker** declares that MEMBER is a `futures::task::AtomicWaker`. The `(&Waker)`
+///    form generates a function registering a waker, the `()` form one waking it. Both take
+///    `self: Pin<&Self>`. Only available with the `futures` feature.
 ///
 /// The generated projection functions take `self: Pin<&Self>` or `self: Pin<&mut Self>`
 /// (depending on the output type) and return the PROJECTION type or nothing for setters which
 /// destroy the old value in place.
 ///
+/// The generated code refers to fully qualified paths, it doesn't need `Pin` to be imported.
+/// Most forms only use `core`. The `box`, `rc` and `arc` forms need the `alloc` feature, the
+/// `std` lock forms `std` and the feature gated forms the crate they are made for.
+///
 /// Note that almost all possible combinations (except unnamed setters) are provided. Not all
 /// of the combinations make necessary sense but are provided for completeness.
 ///
+/// # Custom mappings
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Node(u32);
+///
+/// struct Queue {
+///     head: Node,
+/// }
+///
+/// struct Scheduler {
+///     queue: Queue,
+/// }
+///
+/// impl Scheduler {
+///     project!(unsafe queue |queue| &mut queue.head.0 as head() -> Pin<&mut u32>);
+///     project!(unsafe queue |queue| &queue.head.0 as head_ref() -> &u32);
+/// }
+///
+/// let mut scheduler = Box::pin(Scheduler { queue: Queue { head: Node(1) } });
+/// unsafe {
+///     *scheduler.as_mut().head() += 1;
+///     assert_eq!(*scheduler.as_ref().head_ref(), 2);
+/// }
+/// ```
+///
+/// # Pairs
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Example {
+///     pinned: u32,
+///     counter: u64,
+/// }
+///
+/// impl Example {
+///     // pinned() -> Pin<&u32> and pinned_mut() -> Pin<&mut u32>
+///     project!(pinned, pinned_mut() -> Pin<&(mut) u32>);
+///     // count() -> &u64 and count_mut() -> &mut u64
+///     project!(not_pinned counter as count(), count_mut() -> &(mut) u64);
+/// }
+///
+/// let mut example = Box::pin(Example { pinned: 1, counter: 2 });
+/// *example.as_mut().pinned_mut() += 1;
+/// *example.as_mut().count_mut() += 1;
+/// assert_eq!(*example.as_ref().pinned(), 2);
+/// assert_eq!(*example.as_ref().count(), 3);
+/// ```
+///
+/// # Sub-slices
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Frame {
+///     buffer: [u8; 8],
+/// }
+///
+/// impl Frame {
+///     project!(buffer as window[..] -> Pin<&mut [u8]>);
+/// }
+///
+/// let mut frame = Box::pin(Frame { buffer: [0; 8] });
+/// frame.as_mut().window(2..4).copy_from_slice(&[1, 2]);
+/// assert_eq!(frame.buffer, [0, 0, 1, 2, 0, 0, 0, 0]);
+/// ```
+///
+/// # Pinned elements
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Frames {
+///     frames: Vec<u32>,
+///     slots: [u32; 4],
+/// }
+///
+/// impl Frames {
+///     project!(frames -> Pin<&mut [u32]>);
+///     project!(slots -> Pin<&[u32]>);
+/// }
+///
+/// let mut frames = Box::pin(Frames { frames: vec![1, 2, 3], slots: [0; 4] });
+/// for frame in frames.as_mut().frames().get_mut() {
+///     *frame += 1;
+/// }
+/// assert_eq!(frames.frames, [2, 3, 4]);
+/// assert_eq!(frames.as_ref().slots().len(), 4);
+/// ```
+///
+/// # Downcasting
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::any::Any;
+///
+/// struct Host {
+///     plugin: Box<dyn Any + Send>,
+/// }
+///
+/// impl Host {
+///     project!(plugin as plugin_as<T>() -> downcast Option<Pin<&mut T>>);
+///     project!(plugin as plugin_ref<T>() -> downcast Option<Pin<&T>>);
+/// }
+///
+/// let mut host = Box::pin(Host { plugin: Box::new(1u32) });
+/// if let Some(mut counter) = host.as_mut().plugin_as::<u32>() {
+///     *counter += 1;
+/// }
+/// assert!(host.as_mut().plugin_as::<String>().is_none());
+/// assert_eq!(host.as_ref().plugin_ref::<u32>().as_deref(), Some(&2));
+/// ```
+///
+/// # Array elements
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Ring {
+///     slots: [u32; 4],
+/// }
+///
+/// impl Ring {
+///     project!(slots as slot<const I>() -> Pin<&mut u32>);
+///     project!(slots as slot_ref<const I>() -> &u32);
+/// }
+///
+/// let mut ring = Box::pin(Ring { slots: [0; 4] });
+/// *ring.as_mut().slot::<3>() = 7;
+/// assert_eq!(*ring.as_ref().slot_ref::<3>(), 7);
+/// ```
+///
+/// An index out of bounds is rejected at compile time:
+///
+/// ```compile_fail
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// # struct Ring {
+/// #     slots: [u32; 4],
+/// # }
+/// # impl Ring {
+/// #     project!(slots as slot_ref<const I>() -> &u32);
+/// # }
+/// let ring = Box::pin(Ring { slots: [0; 4] });
+/// ring.as_ref().slot_ref::<4>();
+/// ```
+///
+/// # Locking
+///
+/// ```
+/// # use pin_projections::project;
+/// use std::sync::{Mutex, RwLock};
+///
+/// struct Shared {
+///     state: Mutex<u32>,
+///     config: RwLock<String>,
+/// }
+///
+/// impl Shared {
+///     project!(state as lock_state() -> std MutexGuard<u32>);
+///     project!(config as read_config() -> std RwLockReadGuard<String>);
+///     project!(config as write_config() -> std RwLockWriteGuard<String>);
+/// }
+///
+/// let shared = Box::pin(Shared { state: Mutex::new(1), config: RwLock::new("a".into()) });
+/// *shared.as_ref().lock_state().unwrap() += 1;
+/// shared.as_ref().write_config().unwrap().push('b');
+/// assert_eq!(*shared.as_ref().lock_state().unwrap(), 2);
+/// assert_eq!(*shared.as_ref().read_config().unwrap(), "ab");
+///
+/// // a panic while the lock is held poisons it, the guard is still available
+/// let _ = std::panic::catch_unwind(|| {
+///     let _guard = shared.as_ref().lock_state().unwrap();
+///     panic!("poisoning");
+/// });
+/// let state = shared.as_ref().lock_state().unwrap_or_else(|poisoned| poisoned.into_inner());
+/// assert_eq!(*state, 2);
+/// ```
+///
+/// # Guarded projections
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// #[derive(PartialEq)]
+/// enum State {
+///     Open,
+///     Closed,
+/// }
+///
+/// struct Connection {
+///     socket: u32,
+///     state: State,
+/// }
+///
+/// impl Connection {
+///     project!(socket as socket_if_open() -> Option<Pin<&mut u32>> if self.state == State::Open);
+/// }
+///
+/// let mut connection = Box::pin(Connection { socket: 3, state: State::Open });
+/// assert_eq!(connection.as_mut().socket_if_open().map(|socket| *socket), Some(3));
+/// unsafe { connection.as_mut().get_unchecked_mut().state = State::Closed };
+/// assert!(connection.as_mut().socket_if_open().is_none());
+/// ```
+///
+/// # Swapping between instances
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Slot {
+///     task: Option<Box<u32>>,
+/// }
+///
+/// impl Slot {
+///     project!(not_pinned task as swap_task(Pin<&mut Self>));
+/// }
+///
+/// let mut busy = Box::pin(Slot { task: Some(Box::new(1)) });
+/// let mut idle = Box::pin(Slot { task: None });
+/// idle.as_mut().swap_task(busy.as_mut());
+/// assert_eq!(busy.task, None);
+/// assert_eq!(idle.task.as_deref(), Some(&1));
+/// ```
+///
+/// # Shared members
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::rc::Rc;
+/// use std::sync::Arc;
+///
+/// struct Cache {
+///     entry: Pin<Arc<String>>,
+///     parent: Rc<u32>,
+/// }
+///
+/// impl Cache {
+///     project!(entry as entry_pinned() -> shared Pin<&String>);
+///     project!(entry as entry() -> shared &String);
+///     project!(parent as parent() -> shared u32);
+/// }
+///
+/// let cache = Box::pin(Cache { entry: Arc::pin("cached".into()), parent: Rc::new(7) });
+/// assert_eq!(cache.as_ref().entry_pinned().len(), 6);
+/// assert_eq!(cache.as_ref().entry(), "cached");
+/// assert_eq!(cache.as_ref().parent(), 7);
+/// ```
+///
+/// # Deref targets
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Request {
+///     path: String,
+///     body: Vec<u8>,
+/// }
+///
+/// impl Request {
+///     project!(path -> deref &str);
+///     project!(body -> deref &[u8]);
+///     project!(body as body_mut() -> deref &mut [u8]);
+/// }
+///
+/// let mut request = Box::pin(Request { path: "/index".into(), body: vec![1, 2] });
+/// request.as_mut().body_mut()[0] = 0;
+/// assert_eq!(request.as_ref().path(), "/index");
+/// assert_eq!(request.as_ref().body(), [0, 2]);
+/// ```
+///
+/// # Pinned results
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Established {
+///     stream: u32,
+/// }
+///
+/// struct HandshakeFailure {
+///     attempts: u32,
+/// }
+///
+/// struct Connection {
+///     state: Result<Established, HandshakeFailure>,
+/// }
+///
+/// impl Connection {
+///     project!(state -> Result<Pin<&Established>, Pin<&HandshakeFailure>>);
+///     project!(
+///         state as state_mut() -> Result<Pin<&mut Established>, Pin<&mut HandshakeFailure>>
+///     );
+/// }
+///
+/// let mut connection = Box::pin(Connection { state: Err(HandshakeFailure { attempts: 1 }) });
+/// if let Err(failure) = connection.as_mut().state_mut() {
+///     unsafe { failure.get_unchecked_mut().attempts += 1 };
+/// }
+/// match connection.as_ref().state() {
+///     Ok(established) => assert_eq!(established.stream, 0),
+///     Err(failure) => assert_eq!(failure.attempts, 2),
+/// }
+/// ```
+///
+/// # Already pinned members
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Parent {
+///     child: Pin<Box<String>>,
+/// }
+///
+/// impl Parent {
+///     project!(child -> pin Pin<&String>);
+///     project!(child as child_mut() -> pin Pin<&mut String>);
+/// }
+///
+/// let mut parent = Box::pin(Parent { child: Box::pin("child".into()) });
+/// let _: Pin<&mut String> = parent.as_mut().child_mut();
+/// assert_eq!(*parent.as_ref().child(), "child");
+/// ```
+///
+/// # Raw identifiers
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Token {
+///     r#type: u8,
+///     r#ref: u32,
+/// }
+///
+/// impl Token {
+///     project!(r#type -> &u8);
+///     project!(r#ref as reference() -> Pin<&mut u32>);
+/// }
+///
+/// let mut token = Box::pin(Token { r#type: 1, r#ref: 2 });
+/// let _: Pin<&mut u32> = token.as_mut().reference();
+/// assert_eq!(*token.as_ref().r#type(), 1);
+/// ```
+///
+/// # Unsized members
+///
+/// The reference, scoped, guarded and lifetime forms work for an unsized last member like
+/// `[Type]`, `dyn Trait` or a `?Sized` generic. The by value forms and setters need a `Sized`
+/// member.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::fmt::Debug;
+///
+/// struct Tailed<T: ?Sized> {
+///     len: usize,
+///     tail: T,
+/// }
+///
+/// impl<T: ?Sized> Tailed<T> {
+///     project!(tail as tail_mut() -> Pin<&mut T>);
+///     project!(not_pinned len -> &usize);
+/// }
+///
+/// impl Tailed<dyn Debug + Send> {
+///     project!(tail as tail_dyn() -> Pin<&dyn Debug + Send>);
+/// }
+///
+/// let mut slice: Pin<Box<Tailed<[u8]>>> = Box::pin(Tailed { len: 3, tail: [1, 2, 3] });
+/// slice.as_mut().tail_mut()[0] = 0;
+/// assert_eq!(slice.tail[..*slice.as_ref().len()], [0, 2, 3]);
+///
+/// let object: Pin<Box<Tailed<dyn Debug + Send>>> = Box::pin(Tailed { len: 0, tail: 42 });
+/// assert_eq!(format!("{:?}", object.as_ref().tail_dyn()), "42");
+/// ```
+///
+/// # Unions
+///
+/// The other forms don't know that MEMBER belongs to a union and generate safe functions,
+/// use the `union` forms for these.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::mem::ManuallyDrop;
+///
+/// union Storage {
+///     number: u64,
+///     text: ManuallyDrop<String>,
+/// }
+///
+/// impl Storage {
+///     project!(unsafe number -> union &u64);
+///     project!(unsafe text as text_mut() -> union Pin<&mut String>);
+/// }
+///
+/// let mut storage = Box::pin(Storage { text: ManuallyDrop::new("pinned".into()) });
+/// unsafe {
+///     storage.as_mut().text_mut().push('!');
+///     assert_eq!(*storage.text, "pinned!");
+///     ManuallyDrop::drop(&mut storage.as_mut().get_unchecked_mut().text);
+///     storage.as_mut().get_unchecked_mut().number = 42;
+///     assert_eq!(*storage.as_ref().number(), 42);
+/// }
+/// ```
+///
+/// # Expected members
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// # use std::future::Future;
+/// # use std::task::{Context, Poll};
+/// struct Retry<F> {
+///     attempt: Option<F>,
+/// }
+///
+/// impl<F: Future> Retry<F> {
+///     // `attempt` is always `Some` while polling
+///     project!(attempt as attempt_expect() -> expect Pin<&mut F>);
+/// }
+///
+/// impl<F: Future> Future for Retry<F> {
+///     type Output = F::Output;
+///
+///     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+///         self.attempt_expect().poll(cx)
+///     }
+/// }
+///
+/// let mut retry = Box::pin(Retry { attempt: Some(std::future::ready(1)) });
+/// let mut cx = Context::from_waker(std::task::Waker::noop());
+/// assert_eq!(retry.as_mut().poll(&mut cx), Poll::Ready(1));
+/// ```
+///
+/// A member that is `None` panics at the caller:
+///
+/// ```should_panic
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Slot {
+///     value: Option<u32>,
+/// }
+///
+/// impl Slot {
+///     project!(value as value_expect() -> expect Pin<&u32>);
+/// }
+///
+/// let slot = Box::pin(Slot { value: None });
+/// slot.as_ref().value_expect();
+/// ```
+///
+/// # Lazy initialization
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// # use std::future::{ready, Future, Ready};
+/// # use std::task::{Context, Poll, Waker};
+/// struct Lazy {
+///     inner: Option<Ready<u32>>,
+/// }
+///
+/// impl Lazy {
+///     project!(inner as inner_or_init() -> get_or_insert_with Pin<&mut Ready<u32>>);
+/// }
+///
+/// let mut lazy = Box::pin(Lazy { inner: None });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let inner = lazy.as_mut().inner_or_init(|| ready(7));
+/// assert_eq!(inner.poll(&mut cx), Poll::Ready(7));
+/// assert!(lazy.inner.is_some());
+/// ```
+///
+/// # Interior mutability
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::cell::UnsafeCell;
+///
+/// struct Node {
+///     value: UnsafeCell<u32>,
+/// }
+///
+/// impl Node {
+///     project!(value as value_ptr() -> cell *mut u32);
+///     project!(unsafe value as value_mut() -> cell Pin<&mut u32>);
+/// }
+///
+/// let node = Box::pin(Node { value: UnsafeCell::new(1) });
+/// unsafe {
+///     *node.as_ref().value_mut() += 1;
+///     assert_eq!(*node.as_ref().value_ptr(), 2);
+/// }
+/// ```
+///
+/// `Cell` members are read and written through `Pin<&Self>`:
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::cell::Cell;
+///
+/// struct Node {
+///     linked: Cell<bool>,
+/// }
+///
+/// impl Node {
+///     project!(pub linked -> cell bool);
+///     project!(pub linked as set_linked(cell bool));
+/// }
+///
+/// let node = Box::pin(Node { linked: Cell::new(false) });
+/// node.as_ref().set_linked(true);
+/// assert!(node.as_ref().linked());
+/// ```
+///
+/// # Manual drop
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::mem::ManuallyDrop;
+///
+/// struct Connection {
+///     buffer: ManuallyDrop<Vec<u8>>,
+///     socket: ManuallyDrop<String>,
+/// }
+///
+/// impl Connection {
+///     project!(buffer as buffer() -> manually_drop Pin<&mut Vec<u8>>);
+///     project!(socket as socket() -> manually_drop &String);
+///     project!(unsafe drop buffer as drop_buffer());
+///     project!(unsafe drop socket as drop_socket());
+/// }
+///
+/// impl Drop for Connection {
+///     fn drop(&mut self) {
+///         // the buffer is dropped before the socket
+///         let mut this = unsafe { Pin::new_unchecked(self) };
+///         unsafe {
+///             this.as_mut().drop_buffer();
+///             this.drop_socket();
+///         }
+///     }
+/// }
+///
+/// let mut connection = Box::pin(Connection {
+///     buffer: ManuallyDrop::new(vec![1]),
+///     socket: ManuallyDrop::new("socket".into()),
+/// });
+/// connection.as_mut().buffer().push(2);
+/// assert_eq!(*connection.buffer, [1, 2]);
+/// assert_eq!(connection.as_ref().socket(), "socket");
+/// ```
+///
+/// # Two-phase initialization
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::mem::MaybeUninit;
+///
+/// struct Lazy {
+///     value: MaybeUninit<String>,
+/// }
+///
+/// impl Lazy {
+///     project!(unsafe value as init_value(String) -> uninit Pin<&mut String>);
+///     project!(unsafe value as value() -> uninit Pin<&mut String>);
+///     project!(unsafe value as value_uninit() -> uninit &mut String);
+/// }
+///
+/// let mut lazy = Box::pin(Lazy { value: MaybeUninit::uninit() });
+/// unsafe {
+///     lazy.as_mut().init_value("early".into()).push_str(" init");
+///     assert_eq!(*lazy.as_mut().value(), "early init");
+///     lazy.as_mut().value_uninit().assume_init_drop();
+/// }
+/// ```
+///
+/// # Byte views
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     len: u8,
+///     checksum: u16,
+/// }
+///
+/// struct Packet {
+///     header: Header,
+/// }
+///
+/// impl Packet {
+///     project!(unsafe header as header_bytes() -> bytes Pin<&Header>);
+///     project!(unsafe header as header_bytes_mut() -> bytes &mut [u8]);
+/// }
+///
+/// let mut packet = Box::pin(Packet { header: Header { kind: 1, len: 2, checksum: 0 } });
+/// unsafe {
+///     packet.as_mut().header_bytes_mut()[1] = 4;
+///     let (header, bytes) = packet.as_ref().header_bytes();
+///     assert_eq!(header.len, 4);
+///     assert_eq!(&bytes[..2], [1, 4]);
+/// }
+/// ```
+///
+/// # Explicit lifetimes
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Inner<'a>(&'a str);
+///
+/// struct Wrapper<'a> {
+///     inner: Inner<'a>,
+/// }
+///
+/// impl<'a> Wrapper<'a> {
+///     project!(inner as inner<'s>() -> Pin<&'s mut Inner<'a>>);
+///     project!(inner as inner_ref<'s>() -> &'s Inner<'a>);
+/// }
+///
+/// let mut wrapper = Box::pin(Wrapper { inner: Inner("pinned") });
+/// let inner: Pin<&mut Inner<'_>> = wrapper.as_mut().inner();
+/// assert_eq!(inner.0, "pinned");
+/// assert_eq!(wrapper.as_ref().inner_ref().0, "pinned");
+/// ```
+///
+/// # Where clauses
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::future::Future;
+/// # use std::pin::Pin;
+/// struct Wrapper<T> {
+///     inner: T,
+/// }
+///
+/// impl<T> Wrapper<T> {
+///     project!(inner -> Pin<&mut T> where T: Future);
+///     project!(inner as get_inner() -> T where T: Clone);
+/// }
+///
+/// let wrapper = Box::pin(Wrapper { inner: 42 });
+/// assert_eq!(wrapper.as_ref().get_inner(), 42);
+/// ```
+///
+/// # Polling
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::future::{ready, Future, Ready};
+/// use std::task::{Context, Poll, Waker};
+///
+/// struct Both {
+///     first: Ready<u8>,
+///     second: Ready<u8>,
+///     last: Poll<u8>,
+/// }
+///
+/// impl Both {
+///     project!(first as poll_first() -> poll u8);
+///     project!(second as poll_second() -> poll u8);
+///     // a `Poll` member is cloned like any other type
+///     project!(last -> Poll<u8>);
+///     project!(last as set_last(Poll<u8>));
+/// }
+///
+/// impl Future for Both {
+///     type Output = u8;
+///
+///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+///         let result = match self.as_mut().poll_first(cx) {
+///             Poll::Ready(0) => self.as_mut().poll_second(cx),
+///             other => other,
+///         };
+///         self.set_last(result);
+///         result
+///     }
+/// }
+///
+/// let mut both = Box::pin(Both { first: ready(0), second: ready(2), last: Poll::Pending });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(both.as_mut().poll(&mut cx), Poll::Ready(2));
+/// assert_eq!(both.as_ref().last(), Poll::Ready(2));
+/// ```
+///
+/// Types from modules named like a keyword are cloned by the plain getter and setter:
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// mod cell {
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     pub struct Id(pub u32);
+/// }
+///
+/// mod poll {
+///     #[derive(Clone, Debug, PartialEq)]
+///     pub enum State {
+///         Pending,
+///         Done,
+///     }
+/// }
+///
+/// struct Task {
+///     id: cell::Id,
+///     state: poll::State,
+/// }
+///
+/// impl Task {
+///     project!(id -> cell::Id);
+///     project!(state -> poll::State);
+///     project!(state as set_state(poll::State));
+/// }
+///
+/// let mut task = Box::pin(Task { id: cell::Id(3), state: poll::State::Pending });
+/// task.as_mut().set_state(poll::State::Done);
+/// assert_eq!(task.as_ref().id(), cell::Id(3));
+/// assert_eq!(task.as_ref().state(), poll::State::Done);
+/// ```
+///
+/// # Safe projections
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Wrapper<T> {
+///     inner: T,
+///     counter: u32,
+/// }
+///
+/// impl<T> Wrapper<T> {
+///     project!(safe inner as inner_mut() -> Pin<&mut T>);
+///     project!(safe counter -> &mut u32);
+/// }
+///
+/// let mut wrapper = Wrapper { inner: 1u8, counter: 0 };
+/// let mut pinned = Pin::new(&mut wrapper);
+/// *pinned.as_mut().inner_mut() += 1;
+/// *pinned.as_mut().counter() += 1;
+/// assert_eq!((wrapper.inner, wrapper.counter), (2, 1));
+/// ```
+///
+/// Once the object is known to be movable, the `into` forms hand out a member by value:
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Finished<Io> {
+///     io: Io,
+///     written: usize,
+/// }
+///
+/// impl<Io> Finished<Io> {
+///     project!(safe io as into_io() -> into Io);
+/// }
+///
+/// let finished = Finished { io: vec![1u8, 2], written: 2 };
+/// assert_eq!(finished.into_io(), [1, 2]);
+/// ```
+///
+/// These are not available when the member is not `Unpin`:
+///
+/// ```compile_fail
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::marker::PhantomPinned;
+///
+/// struct Wrapper<T> {
+///     inner: T,
+/// }
+///
+/// impl<T> Wrapper<T> {
+///     project!(safe inner as inner_mut() -> Pin<&mut T>);
+/// }
+///
+/// let mut wrapper = Box::pin(Wrapper { inner: PhantomPinned });
+/// wrapper.as_mut().inner_mut();
+/// ```
+///
+/// # Promoting unpinned receivers
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// use std::marker::PhantomPinned;
+///
+/// struct Node {
+///     value: u32,
+///     _pinned: PhantomPinned,
+/// }
+///
+/// struct List {
+///     head: Box<Node>,
+/// }
+///
+/// impl Node {
+///     project!(not_pinned value -> &mut u32);
+/// }
+///
+/// impl List {
+///     // SAFETY: the head node is boxed and never moved out of its box
+///     project!(unsafe promote head as head() -> Pin<&mut Node>);
+/// }
+///
+/// let mut list = List { head: Box::new(Node { value: 1, _pinned: PhantomPinned }) };
+/// *unsafe { list.head() }.value() += 1;
+/// assert_eq!(list.head.value, 2);
+/// ```
+///
+/// # Reborrowing
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Example {
+///     first: u32,
+///     second: u32,
+/// }
+///
+/// impl Example {
+///     project!(self as reborrow() -> Pin<&mut Self>);
+///     project!(self as downgrade() -> Pin<&Self>);
+///     project!(first -> &mut u32);
+///     project!(second -> &mut u32);
+///     project!(second as second_ref() -> &u32);
+///
+///     fn swap(mut self: Pin<&mut Self>) -> u32 {
+///         let first = *self.reborrow().first();
+///         *self.reborrow().first() = *self.reborrow().second();
+///         *self.reborrow().second() = first;
+///         *self.downgrade().second_ref()
+///     }
+/// }
+///
+/// let mut example = Box::pin(Example { first: 1, second: 2 });
+/// assert_eq!(example.as_mut().swap(), 1);
+/// assert_eq!(example.first, 2);
+/// ```
+///
+/// # Trait implementations
+///
+/// Without a visibility the generated functions are plain `fn`s, these can implement trait
+/// methods with the same signature.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// trait Counter {
+///     fn count(self: Pin<&mut Self>) -> &mut u64;
+///     fn pinned(self: Pin<&Self>) -> Pin<&String>;
+/// }
+///
+/// struct Example {
+///     count: u64,
+///     name: String,
+/// }
+///
+/// impl Counter for Example {
+///     project!(count -> &mut u64);
+///     project!(name as pinned() -> Pin<&String>);
+/// }
+///
+/// let mut example = Box::pin(Example { count: 0, name: "example".into() });
+/// *example.as_mut().count() += 1;
+/// assert_eq!(example.count, 1);
+/// assert_eq!(*example.as_ref().pinned(), "example");
+/// ```
+///
+/// # Documentation
+///
+/// Doc comments in front of a declaration document the generated function. Without these
+/// it gets a generated summary naming the member, the receiver and whether the member is
+/// structurally pinned, public projections don't trigger the `missing_docs` lint.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// pub struct Example {
+///     pinned: u32,
+/// }
+///
+/// impl Example {
+///     project! {
+///         /// The pinned member.
+///         pub pinned -> Pin<&u32>;
+///         // documented as "Projects the member `pinned` of `Pin<&mut Self>` to
+///         // `Pin<&mut u32>`, the member is structurally pinned."
+///         pub pinned as pinned_mut() -> Pin<&mut u32>;
+///     }
+/// }
+/// ```
+///
+/// # Stable entry points
+///
+/// With `#[stable($NAME)]` a second, `#[doc(hidden)]` function NAME with the same signature
+/// and body is generated. Code generated by other macros can call it as `Type::NAME(pinned)`
+/// and keeps working when the public projection is renamed later.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// pub struct Example {
+///     pinned: u32,
+/// }
+///
+/// impl Example {
+///     project!(#[stable(__pinned_v1)] pub pinned as pinned_ref() -> Pin<&u32>);
+/// }
+///
+/// let example = Box::pin(Example { pinned: 42 });
+/// assert_eq!(*Example::__pinned_v1(example.as_ref()), 42);
+/// assert_eq!(*example.as_ref().pinned_ref(), 42);
+/// ```
+///
+/// # Inlining
+///
+/// The generated functions are `#[inline]`. A declaration preceded by `#[inline(always)]` or
+/// `#[inline(never)]` gets that attribute instead, `#[no_inline]` omits it and leaves the
+/// decision to the compiler.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Connection {
+///     state: u32,
+///     name: String,
+/// }
+///
+/// impl Connection {
+///     project! {
+///         #[inline(always)]
+///         state as state_mut() -> Pin<&mut u32>;
+///         #[no_inline]
+///         name as set_name(String);
+///     }
+/// }
+///
+/// let mut connection = Box::pin(Connection { state: 0, name: String::new() });
+/// *connection.as_mut().state_mut() += 1;
+/// connection.as_mut().set_name("idle".into());
+/// assert_eq!((connection.state, connection.name.as_str()), (1, "idle"));
+/// ```
+///
+/// # Policies
+///
+/// An invocation starting with `policy = path::to::macro;` passes every function it generates
+/// to that macro instead of emitting it directly. The macro receives the whole function as
+/// `item` and emits it, usually with extra attributes required by an organization. Other
+/// invocations are not affected, a crate that wants the policy everywhere wraps `project!` in
+/// a macro of its own.
+///
+/// ```
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// macro_rules! audited {
+///     ($item:item) => {
+///         #[must_use]
+///         #[deny(unsafe_op_in_unsafe_fn)]
+///         $item
+///     };
+/// }
+///
+/// struct Timer {
+///     deadline: u64,
+/// }
+///
+/// impl Timer {
+///     project! {
+///         policy = audited;
+///         deadline -> Pin<&mut u64>;
+///         deadline as deadline_ref() -> &u64;
+///     }
+/// }
+///
+/// let mut timer = Box::pin(Timer { deadline: 10 });
+/// *timer.as_mut().deadline() += 5;
+/// assert_eq!(*timer.as_ref().deadline_ref(), 15);
+/// ```
+///
+/// # Usage examples
+///
+/// With the `doc-examples` feature an invocation starting with `example = path::to::Type;`
+/// adds an example section to the documentation of every function it generates. TYPE is the
+/// full path of `Self` as seen by the doctests of the defining crate, the examples are
+/// compiled as `no_run` doctests there. Without the feature `example` has no effect.
+///
+/// ```
+/// pub mod timer {
+///     use pin_projections::project;
+///
+///     pub struct Timer {
+///         deadline: u64,
+///     }
+///
+///     impl Timer {
+///         project! {
+///             example = my_crate::timer::Timer;
+///             pub deadline -> Pin<&mut u64>;
+///         }
+///     }
+/// }
+/// ```
+///
+/// # Name collisions
+///
+/// Projections for the same member without an explicit FUNCTION name collide. The compiler
+/// reports the offending `project!` invocations as duplicate definitions, give at least one of
+/// them a distinct name:
+///
+/// ```compile_fail,E0592
+/// # use pin_projections::project;
+/// # use std::pin::Pin;
+/// struct Example {
+///     member: u32,
+/// }
+///
+/// impl Example {
+///     project!(member -> &u32);
+///     project!(member -> Pin<&mut u32>);
+/// }
+/// ```
+///
 /// # SAFETY
 ///
 /// See [projections and structural pinning](https://doc.rust-lang.org/std/pin/index.html#projections-and-structural-pinning)
@@ -45,173 +1629,1579 @@
 /// This library provides a thin (zero cost, fast to compile) wrapper for generating
 /// projection functions. The safety thereof lies in the hands of the user!
 ///
+/// Members of `#[repr(packed)]` structs must not be projected. The compiler rejects
+/// references to members that may be unaligned, the `PinProjections` derive rejects packed
+/// structs as a whole.
+///
 #[macro_export]
 macro_rules! project {
-    // named, immutable, structurally pinned
-    ($P:vis $M:ident as $N:ident() -> Pin<&$T:ty>) => {
-        #[inline]
-        $P fn $N(self: Pin<&Self>) -> Pin<&$T> {
-            unsafe { self.map_unchecked(|s| &s.$M) }
+    // emits a projection function, every form ends up here, documented unless docs are given,
+    // forms projecting to a member that is not structurally pinned name their pinning
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt pinning $K:literal
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*)
+        -> ::core::pin::Pin<&$($L:lifetime)? mut $T:ty> $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(pinned mut $M ($($params)*) $T, $K)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> ::core::pin::Pin<&$($L)? mut $T> $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt pinning $K:literal
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*)
+        -> ::core::pin::Pin<&$L:lifetime $T:ty> $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(pinned $M ($($params)*) $T, $K)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> ::core::pin::Pin<&$L $T> $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt pinning $K:literal
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) -> ::core::pin::Pin<&$T:ty> $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(pinned $M ($($params)*) $T, $K)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> ::core::pin::Pin<&$T> $body
+        );
+    };
+    (@fn $V:tt $U:tt pinning $K:literal $($rest:tt)*) => {
+        $crate::project!(@fn $V $U $($rest)*);
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*)
+        -> ::core::pin::Pin<&$($L:lifetime)? mut $T:ty> $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(pinned mut $M ($($params)*) $T)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> ::core::pin::Pin<&$($L)? mut $T> $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*)
+        -> ::core::pin::Pin<&$L:lifetime $T:ty> $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(pinned $M ($($params)*) $T)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> ::core::pin::Pin<&$L $T> $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) -> ::core::pin::Pin<&$T:ty> $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(pinned $M ($($params)*) $T)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> ::core::pin::Pin<&$T> $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*)
+        -> &$($L:lifetime)? mut $T:ty $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(unpinned mut $M ($($params)*) $T)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> &$($L)? mut $T $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) -> &$L:lifetime $T:ty $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(unpinned $M ($($params)*) $T)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> &$L $T $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) -> &$T:ty $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!(unpinned $M ($($params)*) $T)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) -> &$T $body
+        );
+    };
+    (@fn
+        [[$P:vis] $S:tt [] $($W:tt)*] $U:tt
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) $(-> $R:ty)? $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] $S [#[doc = $crate::__doc!($M ($($params)*) $(-> $R)?)]] $($W)*] $U
+            $M $N $([$($G)*])? ($($params)*) $(-> $R)? $body
+        );
+    };
+    (@fn
+        [[$P:vis] [$S:ident] $D:tt [$($T:ident)? [$O:tt $($I:tt)*]] $($W:tt)*] [$($U:tt)*]
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) $(-> $R:ty)? $body:block
+    ) => {
+        $crate::project!(@fn
+            [[$P] [] $D [$($T)? [$O $($I)*]] $($W)*] [$($U)*]
+            $M $N $([$($G)*])? ($($params)*) $(-> $R)? $body
+        );
+
+        $crate::__policy! {
+            $O
+            #[doc(hidden)]
+            $($I)*
+            $P $($U)* fn $S<$($($G)*)?>($($params)*) $(-> $R)? where $($W)* $body
+        }
+    };
+    (@fn
+        [[$P:vis] [] [$($D:tt)*] [[$O:tt $($I:tt)*]] $($W:tt)*] [$($U:tt)*]
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) $(-> $R:ty)? $body:block
+    ) => {
+        $crate::__doc_example! {
+            $O
+            $N [$($U)*] [$($($G)*)?] ($($params)*)
+            [$($D)*]
+            $($I)*
+            $P $($U)* fn $N<$($($G)*)?>($($params)*) $(-> $R)? where $($W)* {
+                $crate::__report!($N);
+                $body
+            }
+        }
+    };
+    (@fn
+        [[$P:vis] [] [$($D:tt)*] [traced [$O:tt $($I:tt)*]] $($W:tt)*] [async $($U:tt)*]
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) $(-> $R:ty)? $body:block
+    ) => {
+        // `#[track_caller]` has no effect on async functions
+        $crate::__doc_example! {
+            $O
+            $N [async $($U)*] [$($($G)*)?] ($($params)*)
+            [$($D)*]
+            $($I)*
+            $P async $($U)* fn $N<$($($G)*)?>($($params)*) $(-> $R)? where $($W)* {
+                $crate::__report!($N);
+                $crate::__trace!($M $N);
+                $body
+            }
         }
     };
-    ($P:vis unsafe $M:ident as $N:ident() -> Pin<&$T:ty>) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&Self>) -> Pin<&$T> {
-            self.map_unchecked(|s| &s.$M)
+    (@fn
+        [[$P:vis] [] [$($D:tt)*] [traced [$O:tt $($I:tt)*]] $($W:tt)*] [$($U:tt)*]
+        $M:tt $N:ident $([$($G:tt)*])? ($($params:tt)*) $(-> $R:ty)? $body:block
+    ) => {
+        $crate::__doc_example! {
+            $O
+            $N [$($U)*] [$($($G)*)?] ($($params)*)
+            [$($D)*]
+            $($I)*
+            #[track_caller]
+            $P $($U)* fn $N<$($($G)*)?>($($params)*) $(-> $R)? where $($W)* {
+                $crate::__report!($N);
+                $crate::__trace!($M $N);
+                $body
+            }
         }
     };
 
-    // named, mutable, structurally pinned
-    ($P:vis $M:ident as $N:ident() -> Pin<&mut $T:ty>) => {
-        #[inline]
-        $P fn $N(self: Pin<&mut Self>) -> Pin<&mut $T> {
-            unsafe { self.map_unchecked_mut(|s| &mut s.$M) }
-        }
+    // not structurally pinned, asserted to be Unpin
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident() -> &mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut $T {
+            fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+            assert_unpin::<$T>();
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M }
+        });
     };
-    ($P:vis unsafe $M:ident as $N:ident() -> Pin<&mut $T:ty>) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&mut Self>) -> Pin<&mut $T> {
-            self.map_unchecked_mut(|s| &mut s.$M)
-        }
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident() -> &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> &$T {
+            fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+            assert_unpin::<$T>();
+            &::core::pin::Pin::get_ref(self).$M
+        });
+    };
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident() -> |&mut $T:ty|) => {
+        $crate::project!(@fn $V $U $M $N[__R](
+            self: ::core::pin::Pin<&mut Self>,
+            f: impl ::core::ops::FnOnce(&mut $T) -> __R,
+        ) -> __R {
+            fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+            assert_unpin::<$T>();
+            f(unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M })
+        });
+    };
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> &$R:lifetime mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N[$L](self: ::core::pin::Pin<&$L mut Self>) -> &$R mut $T {
+            fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+            assert_unpin::<$T>();
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M }
+        });
+    };
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> &$R:lifetime $T:ty) => {
+        $crate::project!(@fn $V $U $M $N[$L](self: ::core::pin::Pin<&$L Self>) -> &$R $T {
+            fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+            assert_unpin::<$T>();
+            &::core::pin::Pin::get_ref(self).$M
+        });
+    };
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident(Pin<&mut Self>)) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+            other: ::core::pin::Pin<&mut Self>,
+        ) {
+            fn swap_unpin<T: ::core::marker::Unpin>(a: &mut T, b: &mut T) {
+                ::core::mem::swap(a, b)
+            }
+            unsafe {
+                swap_unpin(
+                    &mut ::core::pin::Pin::get_unchecked_mut(self).$M,
+                    &mut ::core::pin::Pin::get_unchecked_mut(other).$M,
+                )
+            }
+        });
+    };
+    (@not_pinned $V:tt $U:tt $M:ident as $N:ident(), $NM:ident() -> &(mut) $T:ty) => {
+        $crate::project!(@not_pinned $V $U $M as $N() -> &$T);
+        $crate::project!(@not_pinned $V $U $M as $NM() -> &mut $T);
+    };
+    (@not_pinned $V:tt $U:tt $M:ident, $NM:ident() -> $($rest:tt)+) => {
+        $crate::project!(@not_pinned $V $U $M as $M(), $NM() -> $($rest)+);
+    };
+    (@not_pinned $V:tt $U:tt $M:ident -> $($rest:tt)+) => {
+        $crate::project!(@not_pinned $V $U $M as $M() -> $($rest)+);
+    };
+    (@not_pinned $V:tt $U:tt $M:ident : $T:ty) => {
+        $crate::project!(@not_pinned $V $U $M as $M() -> &mut $T);
+    };
+    (@not_pinned $V:tt $U:tt $($input:tt)*) => {
+        $crate::project!(@error
+            "invalid input `not_pinned ", ::core::stringify!($($input)*), "`"
+        );
     };
 
-    // named, immutable, not structurally pinned
-    ($P:vis $M:ident as $N:ident() -> &$T:ty) => {
-        #[inline]
-        $P fn $N(self: Pin<&Self>) -> &$T {
-            &self.get_ref().$M
-        }
+    (@form $V:tt $U:tt not_pinned $M:ident $($rest:tt)*) => {
+        $crate::project!(@not_pinned $V $U $M $($rest)*);
     };
-    ($P:vis unsafe $M:ident as $N:ident() -> &$T:ty) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&Self>) -> &$T {
-            &self.get_ref().$M
-        }
+
+    // safe, Self and the member are Unpin
+    (@form [$P:tt $S:tt $D:tt $T:tt $($W:tt)*] $U:tt
+        safe box $M:ident as $N:ident() -> into $R:ty
+    ) => {
+        $crate::project!(@fn [$P $S $D $T Self: ::core::marker::Unpin, $($W)*] $U $M $N(
+            self: $crate::__pin_box!(),
+        ) -> $R {
+            let this = ::core::pin::Pin::into_inner(self);
+            this.$M
+        });
+    };
+    (@form $V:tt $U:tt safe $M:ident as $N:ident() -> $($R:tt)+) => {
+        $crate::project!(@safe $V $U $M $N $($R)+);
+    };
+    (@form $V:tt $U:tt safe $M:ident -> $($R:tt)+) => {
+        $crate::project!(@form $V $U safe $M as $M() -> $($R)+);
+    };
+    (@safe [$P:tt $S:tt $D:tt $T:tt $($W:tt)*] $U:tt $M:ident $N:ident Pin<&mut $R:ty>) => {
+        $crate::project!(@fn
+            [$P $S $D $T Self: ::core::marker::Unpin, $R: ::core::marker::Unpin, $($W)*] $U
+            $M $N(self: ::core::pin::Pin<&mut Self>) -> ::core::pin::Pin<&mut $R>
+        {
+            ::core::pin::Pin::new(&mut ::core::pin::Pin::get_mut(self).$M)
+        });
+    };
+    (@safe [$P:tt $S:tt $D:tt $T:tt $($W:tt)*] $U:tt $M:ident $N:ident Pin<&$R:ty>) => {
+        $crate::project!(@fn [$P $S $D $T $R: ::core::marker::Unpin, $($W)*] $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&$R> {
+            ::core::pin::Pin::new(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@safe [$P:tt $S:tt $D:tt $T:tt $($W:tt)*] $U:tt $M:ident $N:ident &mut $R:ty) => {
+        $crate::project!(@fn [$P $S $D $T Self: ::core::marker::Unpin, $($W)*] $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> &mut $R {
+            &mut ::core::pin::Pin::get_mut(self).$M
+        });
+    };
+    (@safe $V:tt $U:tt $M:ident $N:ident &$R:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> &$R {
+            &::core::pin::Pin::get_ref(self).$M
+        });
+    };
+    (@safe [$P:tt $S:tt $D:tt $T:tt $($W:tt)*] $U:tt $M:ident $N:ident into $R:ty) => {
+        $crate::project!(@fn [$P $S $D $T Self: ::core::marker::Unpin, $($W)*] $U $M $N(
+            self: Self,
+        ) -> $R {
+            self.$M
+        });
+    };
+    (@safe $V:tt $U:tt $M:ident $N:ident $($R:tt)*) => {
+        $crate::project!(@error
+            "`safe` projections are `Pin<&Type>`, `Pin<&mut Type>`, `&Type`, `&mut Type` or ",
+            "`into Type`, ",
+            "found `", ::core::stringify!($($R)*), "`"
+        );
     };
 
-    // named, mutable, not structurally pinned
-    ($P:vis $M:ident as $N:ident() -> &mut $T:ty) => {
-        #[inline]
-        $P fn $N(self: Pin<&mut Self>) -> &mut $T {
-            unsafe { &mut self.get_unchecked_mut().$M }
-        }
+    // drops a ManuallyDrop<Type> member in place
+    (@form $V:tt [unsafe] drop $M:ident as $N:ident()) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: ::core::pin::Pin<&mut Self>) {
+            unsafe {
+                ::core::mem::ManuallyDrop::drop(&mut ::core::pin::Pin::get_unchecked_mut(self).$M)
+            }
+        });
     };
-    ($P:vis unsafe $M:ident as $N:ident() -> &mut $T:ty) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&mut Self>) -> &mut $T {
-            &mut self.get_unchecked_mut().$M
-        }
+    (@form $V:tt [] drop $M:ident as $N:ident()) => {
+        $crate::project!(@error "`drop ", ::core::stringify!($M), "` must be declared `unsafe`");
     };
 
-    // unnamed, immutable, structurally pinned
-    ($P:vis $M:ident -> Pin<&$T:ty>) => {
-        #[inline]
-        $P fn $M(self: Pin<&Self>) -> Pin<&$T> {
-            unsafe { self.map_unchecked(|s| &s.$M) }
-        }
+    // promotes a member of an unpinned receiver to pinned
+    (@form $V:tt [unsafe] promote $M:ident as $N:ident() -> Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V [unsafe] pinning "promoted to pinned by the caller"
+            $M $N(self: &mut Self) -> ::core::pin::Pin<&mut $T> {
+            unsafe { ::core::pin::Pin::new_unchecked(&mut self.$M) }
+        });
     };
-    ($P:vis unsafe $M:ident -> Pin<&$T:ty>) => {
-        #[inline]
-        $P unsafe fn $M(self: Pin<&Self>) -> Pin<&$T> {
-            self.map_unchecked(|s| &s.$M)
-        }
+    (@form $V:tt [unsafe] promote $M:ident as $N:ident() -> Pin<&$T:ty>) => {
+        $crate::project!(@fn $V [unsafe] pinning "promoted to pinned by the caller"
+            $M $N(self: &Self) -> ::core::pin::Pin<&$T> {
+            unsafe { ::core::pin::Pin::new_unchecked(&self.$M) }
+        });
+    };
+    (@form $V:tt [] promote $M:ident as $N:ident() -> $($rest:tt)*) => {
+        $crate::project!(@error
+            "`promote ", ::core::stringify!($M), "` must be declared `unsafe`"
+        );
     };
 
-    // unnamed, mutable, structurally pinned
-    ($P:vis $M:ident -> Pin<&mut $T:ty>) => {
-        #[inline]
-        $P fn $M(self: Pin<&mut Self>) -> Pin<&mut $T> {
-            unsafe { self.map_unchecked_mut(|s| &mut s.$M) }
-        }
+    // AtomicWaker, needs no mutable projection
+    (@form $V:tt $U:tt atomic_waker $M:ident as $N:ident(&Waker)) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+            waker: &::core::task::Waker,
+        ) {
+            $crate::__atomic_waker!(register ::core::pin::Pin::get_ref(self).$M, waker)
+        });
     };
-    ($P:vis unsafe $M:ident -> Pin<&mut $T:ty>) => {
-        #[inline]
-        $P unsafe fn $M(self: Pin<&mut Self>) -> Pin<&mut $T> {
-            self.map_unchecked_mut(|s| &mut s.$M)
-        }
+    (@form $V:tt $U:tt atomic_waker $M:ident as $N:ident()) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) {
+            $crate::__atomic_waker!(wake ::core::pin::Pin::get_ref(self).$M)
+        });
     };
 
-    // unnamed, immutable, not structurally pinned
-    ($P:vis $M:ident -> &$T:ty) => {
-        #[inline]
-        $P fn $M(self: Pin<&Self>) -> &$T {
-            &self.get_ref().$M
-        }
+    // reborrows the receiver itself
+    (@form $V:tt $U:tt self as $N:ident() -> Pin<&mut Self>) => {
+        $crate::project!(@fn $V $U self $N['__r](
+            self: &'__r mut ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&'__r mut Self> {
+            ::core::pin::Pin::as_mut(self)
+        });
     };
-    ($P:vis unsafe $M:ident -> &$T:ty) => {
-        #[inline]
-        $P unsafe fn $M(self: Pin<&Self>) -> &$T {
-            &self.get_ref().$M
-        }
+    (@form $V:tt $U:tt self as $N:ident() -> Pin<&Self>) => {
+        $crate::project!(@fn $V $U self $N['__r](
+            self: &'__r ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&'__r Self> {
+            ::core::pin::Pin::as_ref(self)
+        });
     };
 
-    // unnamed, mutable, not structurally pinned
-    ($P:vis $M:ident -> &mut $T:ty) => {
-        #[inline]
-        $P fn $M(self: Pin<&mut Self>) -> &mut $T {
-            unsafe { &mut self.get_unchecked_mut().$M }
-        }
+    // Pin<Box<Self>> receivers, saves the as_ref() or as_mut() at the call site
+    (@form $V:tt $U:tt box $M:ident as $N:ident() -> into :: $($R:tt)+) => {
+        $crate::project!(@box_get $V $U $M $N into :: $($R)+);
     };
-    ($P:vis unsafe $M:ident -> &mut $T:ty) => {
-        #[inline]
-        $P unsafe fn $M(self: Pin<&mut Self>) -> &mut $T {
-            &mut self.get_unchecked_mut().$M
-        }
+    (@form $V:tt [unsafe] box $M:ident as $N:ident() -> into $T:ty) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: $crate::__pin_box!()) -> $T {
+            let this = unsafe { ::core::pin::Pin::into_inner_unchecked(self) };
+            this.$M
+        });
+    };
+    (@form $V:tt [] box $M:ident as $N:ident() -> into $T:ty) => {
+        $crate::project!(@error
+            "`into ", ::core::stringify!($T), "` must be declared `unsafe`"
+        );
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident() -> Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(self: &$crate::__pin_box!()) -> ::core::pin::Pin<&$T> {
+            unsafe { ::core::pin::Pin::map_unchecked(::core::pin::Pin::as_ref(self), |s| &s.$M) }
+        });
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident() -> Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: &mut $crate::__pin_box!(),
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(::core::pin::Pin::as_mut(self), |s| &mut s.$M)
+            }
+        });
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident() -> &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: &$crate::__pin_box!()) -> &$T {
+            &::core::pin::Pin::get_ref(::core::pin::Pin::as_ref(self)).$M
+        });
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident() -> &mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: &mut $crate::__pin_box!()) -> &mut $T {
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(::core::pin::Pin::as_mut(self)).$M }
+        });
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident() -> $T:ty) => {
+        $crate::project!(@box_get $V $U $M $N $T);
+    };
+    (@box_get $V:tt $U:tt $M:ident $N:ident $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: &$crate::__pin_box!()) -> $T {
+            ::core::clone::Clone::clone(&::core::pin::Pin::get_ref(::core::pin::Pin::as_ref(self)).$M)
+        });
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident(&$T:ty)) => {
+        $crate::project!(@fn $V $U $M $N(self: &mut $crate::__pin_box!(), from: &$T) {
+            unsafe {
+                ::core::pin::Pin::get_unchecked_mut(::core::pin::Pin::as_mut(self)).$M =
+                    ::core::clone::Clone::clone(from);
+            }
+        });
+    };
+    (@form $V:tt $U:tt box $M:ident as $N:ident($T:ty)) => {
+        $crate::project!(@fn $V $U $M $N(self: &mut $crate::__pin_box!(), from: $T) {
+            unsafe {
+                ::core::pin::Pin::get_unchecked_mut(::core::pin::Pin::as_mut(self)).$M = from;
+            }
+        });
+    };
+    (@form $V:tt $U:tt box $($input:tt)*) => {
+        $crate::project!(@error "invalid input `box ", ::core::stringify!($($input)*), "`");
     };
 
-    // named, getter, by clone
-    ($P:vis $M:ident as $N:ident() -> $T:ty) => {
-        #[inline]
-        $P fn $N(self: Pin<&Self>) -> $T {
-            self.get_ref().$M.clone()
-        }
+    // Pin<Rc<Self>> and Pin<Arc<Self>> receivers, shared projections only
+    (@form $V:tt $U:tt rc $M:ident as $N:ident() -> $($rest:tt)+) => {
+        $crate::project!(@shared $V $U [$crate::__pin_rc!()] $M as $N() -> $($rest)+);
     };
-    ($P:vis unsafe $M:ident as $N:ident() -> $T:ty) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&Self>) -> $T {
-            self.get_ref().$M.clone()
-        }
+    (@form $V:tt $U:tt arc $M:ident as $N:ident() -> $($rest:tt)+) => {
+        $crate::project!(@shared $V $U [$crate::__pin_arc!()] $M as $N() -> $($rest)+);
+    };
+    (@shared $V:tt $U:tt [$($P:tt)*] $M:ident as $N:ident() -> Pin<&mut $T:ty>) => {
+        $crate::project!(@error
+            "`rc` and `arc` projections are shared, found `Pin<&mut ", ::core::stringify!($T), ">`"
+        );
+    };
+    (@shared $V:tt $U:tt [$($P:tt)*] $M:ident as $N:ident() -> &mut $T:ty) => {
+        $crate::project!(@error
+            "`rc` and `arc` projections are shared, found `&mut ", ::core::stringify!($T), "`"
+        );
+    };
+    (@shared $V:tt $U:tt [$($P:tt)*] $M:ident as $N:ident() -> Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(self: &$($P)*) -> ::core::pin::Pin<&$T> {
+            unsafe { ::core::pin::Pin::map_unchecked(::core::pin::Pin::as_ref(self), |s| &s.$M) }
+        });
+    };
+    (@shared $V:tt $U:tt [$($P:tt)*] $M:ident as $N:ident() -> &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: &$($P)*) -> &$T {
+            &::core::pin::Pin::get_ref(::core::pin::Pin::as_ref(self)).$M
+        });
+    };
+    (@shared $V:tt $U:tt [$($P:tt)*] $M:ident as $N:ident() -> $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: &$($P)*) -> $T {
+            ::core::clone::Clone::clone(&::core::pin::Pin::get_ref(::core::pin::Pin::as_ref(self)).$M)
+        });
     };
 
-    // unnamed, getter, by clone
-    ($P:vis $M:ident -> $T:ty) => {
-        #[inline]
-        $P fn $M(self: Pin<&Self>) -> $T {
-            self.get_ref().$M.clone()
-        }
+    // paths like `cell::Type` are no keyword forms, they are plain getters and setters
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> $K:ident :: $($R:tt)+) => {
+        $crate::project!(@get $V $U $M $N $K :: $($R)+);
     };
-    ($P:vis unsafe $M:ident -> $T:ty) => {
-        #[inline]
-        $P unsafe fn $M(self: Pin<&Self>) -> $T {
-            self.get_ref().$M.clone()
-        }
+    (@form $V:tt $U:tt $M:ident as $N:ident($K:ident :: $($R:tt)+)) => {
+        $crate::project!(@set $V $U $M $N $K :: $($R)+);
     };
 
-    // named, setter, by clone
-    ($P:vis $M:ident as $N:ident(&$T:ty)) => {
-        #[inline]
-        $P fn $N(self: Pin<&mut Self>, from: &$T) {
-            unsafe { self.get_unchecked_mut().$M = from.clone() };
-        }
+    // whole slice, the member is a Vec, an array or a slice
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Pin<&[$T:ty]>) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&[$T]> {
+            unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.$M[..]) }
+        });
     };
-    ($P:vis unsafe $M:ident as $N:ident(&$T:ty)) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&mut Self>, from: &$T) {
-            self.get_unchecked_mut().$M = from.clone();
-        }
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Pin<&mut [$T:ty]>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut [$T]> {
+            $crate::__pin_check!(self);
+            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M[..]) }
+        });
     };
 
-    // named, setter by move
-    ($P:vis $M:ident as $N:ident($T:ty)) => {
-        #[inline]
-        $P fn $N(self: Pin<&mut Self>, from: $T) {
-            unsafe { self.get_unchecked_mut().$M = from };
-        }
+    // immutable, structurally pinned
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&$T> {
+            unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.$M) }
+        });
     };
-    ($P:vis unsafe $M:ident as $N:ident($T:ty)) => {
-        #[inline]
-        $P unsafe fn $N(self: Pin<&mut Self>, from: $T) {
-            self.get_unchecked_mut().$M = from;
-        }
+
+    // mutable, structurally pinned
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            $crate::__pin_check!(self);
+            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) }
+        });
+    };
+
+    // immutable, not structurally pinned
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> &$T {
+            &::core::pin::Pin::get_ref(self).$M
+        });
+    };
+
+    // mutable, not structurally pinned
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> &mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut $T {
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M }
+        });
+    };
+
+    // poisoned, the member is a PinPoison<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> poisoned Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::result::Result<::core::pin::Pin<&$T>, $crate::poison::Poisoned> {
+            $crate::poison::PinPoison::get(unsafe {
+                ::core::pin::Pin::map_unchecked(self, |s| &s.$M)
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> poisoned Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::result::Result<::core::pin::Pin<&mut $T>, $crate::poison::Poisoned> {
+            $crate::poison::PinPoison::get_mut(unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M)
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> poisoned |Pin<&mut $T:ty>|) => {
+        $crate::project!(@fn $V $U $M $N[__R](
+            self: ::core::pin::Pin<&mut Self>,
+            f: impl ::core::ops::FnOnce(::core::pin::Pin<&mut $T>) -> __R,
+        ) -> ::core::result::Result<__R, $crate::poison::Poisoned> {
+            $crate::poison::PinPoison::update(
+                unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) },
+                f,
+            )
+        });
+    };
+
+    // pinned by construction, the member is a PinnedField<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> pinned Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&$T> {
+            fn assert_pinned_fields<T: ?Sized + $crate::field::PinnedFields>() {}
+            assert_pinned_fields::<Self>();
+            $crate::field::PinnedField::<$T>::get(unsafe {
+                ::core::pin::Pin::map_unchecked(self, |s| &s.$M)
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> pinned Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            fn assert_pinned_fields<T: ?Sized + $crate::field::PinnedFields>() {}
+            assert_pinned_fields::<Self>();
+            $crate::field::PinnedField::<$T>::get_mut(unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M)
+            })
+        });
+    };
+
+    // union members, a ManuallyDrop<Type> or a Copy Type
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> union Pin<&$T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&$T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked(self, |s| {
+                    <_ as $crate::__UnionField<$T>>::get(&s.$M)
+                })
+            }
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> union Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| {
+                    <_ as $crate::__UnionField<$T>>::get_mut(&mut s.$M)
+                })
+            }
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> union &$T:ty) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: ::core::pin::Pin<&Self>) -> &$T {
+            unsafe { <_ as $crate::__UnionField<$T>>::get(&::core::pin::Pin::get_ref(self).$M) }
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> union &mut $T:ty) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut $T {
+            unsafe {
+                <_ as $crate::__UnionField<$T>>::get_mut(
+                    &mut ::core::pin::Pin::get_unchecked_mut(self).$M,
+                )
+            }
+        });
+    };
+    (@form $V:tt [] $M:ident as $N:ident() -> union $($rest:tt)*) => {
+        $crate::project!(@error
+            "`union ", ::core::stringify!($($rest)*), "` must be declared `unsafe`"
+        );
+    };
+
+    // interior mutability, the member is an UnsafeCell<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> cell *mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> *mut $T {
+            ::core::cell::UnsafeCell::get(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> cell Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::new_unchecked(
+                    &mut *::core::cell::UnsafeCell::get(&::core::pin::Pin::get_ref(self).$M),
+                )
+            }
+        });
+    };
+    (@form $V:tt [] $M:ident as $N:ident() -> cell Pin<&mut $T:ty>) => {
+        $crate::project!(@error
+            "`cell Pin<&mut ", ::core::stringify!($T), ">` must be declared `unsafe`"
+        );
+    };
+
+    // atomic operations, the member is an atomic type
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> atomic $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> $crate::atomic::AtomicOps<'_, $T> {
+            $crate::atomic::AtomicOps::new(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // expected content, the member is an Option<Type> that is Some by invariant
+    (@form [$P:tt $S:tt [] $T:tt $($W:tt)*] $U:tt
+        $M:ident as $N:ident() -> expect $($R:tt)+
+    ) => {
+        $crate::project!(@form [$P $S [
+            #[doc = ::core::concat!(
+                "Projects the content of the member `", ::core::stringify!($M), "`, an `Option`, ",
+                "to `", ::core::stringify!($($R)+), "`."
+            )]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = ""]
+            #[doc = "Panics when the member is `None`."]
+        ] $T $($W)*] $U $M as $N() -> expect $($R)+);
+    };
+    (@form [$P:tt $S:tt [$($D:tt)+] [$I:tt] $($W:tt)*] $U:tt
+        $M:ident as $N:ident() -> expect $($R:tt)+
+    ) => {
+        $crate::project!(@expect [$P $S [$($D)+ #[track_caller]] [$I] $($W)*] $U $M $N $($R)+);
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> expect $($R:tt)+) => {
+        $crate::project!(@expect $V $U $M $N $($R)+);
+    };
+    (@expect $V:tt $U:tt $M:ident $N:ident Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            match unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M } {
+                ::core::option::Option::Some(value) => unsafe {
+                    ::core::pin::Pin::new_unchecked(value)
+                },
+                ::core::option::Option::None => $crate::project!(@expect_failed $M),
+            }
+        });
+    };
+    (@expect $V:tt $U:tt $M:ident $N:ident Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&$T> {
+            match &::core::pin::Pin::get_ref(self).$M {
+                ::core::option::Option::Some(value) => unsafe {
+                    ::core::pin::Pin::new_unchecked(value)
+                },
+                ::core::option::Option::None => $crate::project!(@expect_failed $M),
+            }
+        });
+    };
+    (@expect $V:tt $U:tt $M:ident $N:ident $($R:tt)*) => {
+        $crate::project!(@error
+            "`expect` projections are `Pin<&Type>` or `Pin<&mut Type>`, found `",
+            ::core::stringify!($($R)*), "`"
+        );
+    };
+    (@expect_failed $M:ident) => {
+        ::core::panic!(
+            "member `{}` of `{}` is `None`",
+            ::core::stringify!($M),
+            ::core::any::type_name::<Self>(),
+        )
+    };
+
+    // lazy initialization, the member is an Option<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> get_or_insert_with Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+            init: impl ::core::ops::FnOnce() -> $T,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::new_unchecked(::core::option::Option::get_or_insert_with(
+                    &mut ::core::pin::Pin::get_unchecked_mut(self).$M,
+                    init,
+                ))
+            }
+        });
+    };
+
+    // interior mutability, the member is a Cell<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> cell $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> $T {
+            ::core::cell::Cell::<$T>::get(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident(cell $T:ty)) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>, from: $T) {
+            ::core::cell::Cell::<$T>::set(&::core::pin::Pin::get_ref(self).$M, from)
+        });
+    };
+
+    // two-phase initialization, the member is a MaybeUninit<Type>
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> uninit &mut $T:ty) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> &mut ::core::mem::MaybeUninit<$T> {
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M }
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> uninit Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| {
+                    ::core::mem::MaybeUninit::assume_init_mut(&mut s.$M)
+                })
+            }
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident($F:ty) -> uninit Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+            from: $F,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| {
+                    ::core::mem::MaybeUninit::write(&mut s.$M, from)
+                })
+            }
+        });
+    };
+    (@form $V:tt [] $M:ident as $N:ident($($from:tt)*) -> uninit $($rest:tt)*) => {
+        $crate::project!(@error
+            "`uninit ", ::core::stringify!($($rest)*), "` must be declared `unsafe`"
+        );
+    };
+
+    // optional boxed, the member is an Option<Box<Type>>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> boxed Option<Pin<&mut $T:ty>>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&mut $T>> {
+            $crate::__option_box!(mut unsafe { ::core::pin::Pin::get_unchecked_mut(self) }.$M, $T)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> boxed Option<Pin<&$T:ty>>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&$T>> {
+            $crate::__option_box!(ref ::core::pin::Pin::get_ref(self).$M, $T)
+        });
+    };
+
+    // shared ownership, the member is an Rc<Type>, Arc<Type> or other Deref<Target = Type>,
+    // pinned for the pinned form
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> shared Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U pinning "a shared pointer, only its target is pinned"
+            $M $N(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&$T> {
+            ::core::pin::Pin::as_ref(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> shared &$T:ty) => {
+        $crate::project!(@form $V $U $M as $N() -> deref &$T);
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> shared $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> $T {
+            <$T as ::core::clone::Clone>::clone(::core::ops::Deref::deref(
+                &::core::pin::Pin::get_ref(self).$M,
+            ))
+        });
+    };
+
+    // both variants pinned, the member is a Result<Type, Error>
+    (@form $V:tt $U:tt
+        $M:ident as $N:ident() -> Result<Pin<&mut $T:ty>, Pin<&mut $E:ty>>
+    ) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::result::Result<::core::pin::Pin<&mut $T>, ::core::pin::Pin<&mut $E>> {
+            match unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M } {
+                ::core::result::Result::Ok(value) => {
+                    ::core::result::Result::Ok(unsafe { ::core::pin::Pin::new_unchecked(value) })
+                }
+                ::core::result::Result::Err(error) => {
+                    ::core::result::Result::Err(unsafe { ::core::pin::Pin::new_unchecked(error) })
+                }
+            }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Result<Pin<&$T:ty>, Pin<&$E:ty>>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::result::Result<::core::pin::Pin<&$T>, ::core::pin::Pin<&$E>> {
+            match &::core::pin::Pin::get_ref(self).$M {
+                ::core::result::Result::Ok(value) => {
+                    ::core::result::Result::Ok(unsafe { ::core::pin::Pin::new_unchecked(value) })
+                }
+                ::core::result::Result::Err(error) => {
+                    ::core::result::Result::Err(unsafe { ::core::pin::Pin::new_unchecked(error) })
+                }
+            }
+        });
+    };
+
+    // already pinned, the member is a Pin<Ptr> with Ptr dereferencing to Type
+    (@form [$P:tt $S:tt $D:tt $I:tt $($W:tt)*] $U:tt
+        $M:ident as $N:ident() -> pin Pin<&mut $T:ty>
+    ) => {
+        $crate::project!(@fn [$P $S $D $I Self: ::core::marker::Unpin, $($W)*] $U
+            pinning "an already pinned pointer, only its target is pinned"
+            $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            ::core::pin::Pin::as_mut(&mut ::core::pin::Pin::get_mut(self).$M)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> pin Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U pinning "an already pinned pointer, only its target is pinned"
+            $M $N(self: ::core::pin::Pin<&Self>) -> ::core::pin::Pin<&$T> {
+            ::core::pin::Pin::as_ref(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // deref target, the member is a String, Vec<Type> or other Deref<Target = Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> deref &mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut $T {
+            ::core::ops::DerefMut::deref_mut(unsafe {
+                &mut ::core::pin::Pin::get_unchecked_mut(self).$M
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> deref &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> &$T {
+            ::core::ops::Deref::deref(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // manual drop, the member is a ManuallyDrop<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> manually_drop Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| {
+                    <::core::mem::ManuallyDrop<$T> as ::core::ops::DerefMut>::deref_mut(&mut s.$M)
+                })
+            }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> manually_drop Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&$T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked(self, |s| {
+                    <::core::mem::ManuallyDrop<$T> as ::core::ops::Deref>::deref(&s.$M)
+                })
+            }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> manually_drop &mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut $T {
+            <::core::mem::ManuallyDrop<$T> as ::core::ops::DerefMut>::deref_mut(unsafe {
+                &mut ::core::pin::Pin::get_unchecked_mut(self).$M
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> manually_drop &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> &$T {
+            <::core::mem::ManuallyDrop<$T> as ::core::ops::Deref>::deref(
+                &::core::pin::Pin::get_ref(self).$M,
+            )
+        });
+    };
+
+    // byte views, the member is a #[repr(C)] type without padding
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> bytes Pin<&$T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> (::core::pin::Pin<&$T>, &[u8]) {
+            let member: &$T = &::core::pin::Pin::get_ref(self).$M;
+            unsafe {
+                (
+                    ::core::pin::Pin::new_unchecked(member),
+                    ::core::slice::from_raw_parts(
+                        member as *const $T as *const u8,
+                        ::core::mem::size_of::<$T>(),
+                    ),
+                )
+            }
+        });
+    };
+    (@form $V:tt [unsafe] $M:ident as $N:ident() -> bytes &mut [u8]) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut [u8] {
+            unsafe {
+                let member = ::core::ptr::addr_of_mut!(::core::pin::Pin::get_unchecked_mut(self).$M);
+                ::core::slice::from_raw_parts_mut(
+                    member as *mut u8,
+                    ::core::mem::size_of_val(&*member),
+                )
+            }
+        });
+    };
+    (@form $V:tt [] $M:ident as $N:ident() -> bytes $($rest:tt)*) => {
+        $crate::project!(@error
+            "`bytes ", ::core::stringify!($($rest)*), "` must be declared `unsafe`"
+        );
+    };
+
+    // locking, the member is a std::sync::Mutex<Type> or RwLock<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> std MutexGuard<$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::std::sync::LockResult<::std::sync::MutexGuard<'_, $T>> {
+            ::std::sync::Mutex::lock(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> std RwLockReadGuard<$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::std::sync::LockResult<::std::sync::RwLockReadGuard<'_, $T>> {
+            ::std::sync::RwLock::read(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> std RwLockWriteGuard<$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::std::sync::LockResult<::std::sync::RwLockWriteGuard<'_, $T>> {
+            ::std::sync::RwLock::write(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // parking_lot locking, the member is a parking_lot::Mutex<Type> or RwLock<Type>
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> parking_lot Option<$G:ident<$T:ty>>) => {
+        $crate::project!(@parking_lot $V $U $M $N try $G $T);
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> parking_lot $G:ident<$T:ty>) => {
+        $crate::project!(@parking_lot $V $U $M $N $G $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident MutexGuard $T:ty) => {
+        $crate::project!(@parking_lot $V $U $M $N [lock] MutexGuard $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident RwLockReadGuard $T:ty) => {
+        $crate::project!(@parking_lot $V $U $M $N [read] RwLockReadGuard $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident RwLockWriteGuard $T:ty) => {
+        $crate::project!(@parking_lot $V $U $M $N [write] RwLockWriteGuard $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident try MutexGuard $T:ty) => {
+        $crate::project!(@parking_lot $V $U $M $N [try_lock] Option MutexGuard $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident try RwLockReadGuard $T:ty) => {
+        $crate::project!(@parking_lot $V $U $M $N [try_read] Option RwLockReadGuard $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident try RwLockWriteGuard $T:ty) => {
+        $crate::project!(@parking_lot $V $U $M $N [try_write] Option RwLockWriteGuard $T);
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident [$op:ident] $G:ident $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> $crate::__parking_lot!(guard $G $T) {
+            $crate::__parking_lot!($op ::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident [$op:ident] Option $G:ident $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::option::Option<$crate::__parking_lot!(guard $G $T)> {
+            $crate::__parking_lot!($op ::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@parking_lot $V:tt $U:tt $M:ident $N:ident $($G:tt)*) => {
+        $crate::project!(@error
+            "`parking_lot` projections are `MutexGuard<Type>`, `RwLockReadGuard<Type>` or ",
+            "`RwLockWriteGuard<Type>`, optionally wrapped in `Option`"
+        );
+    };
+
+    // guarded, projects only when the condition on self holds
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Option<Pin<&mut $T:ty>> if $($cond:tt)+) => {
+        $crate::project!(@guard [$V $U $M $N [mut $T] [$($cond)+]] $($cond)+);
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> Option<Pin<&$T:ty>> if $($cond:tt)+) => {
+        $crate::project!(@guard [$V $U $M $N [$T] [$($cond)+]] $($cond)+);
+    };
+
+    // async locking, the member is a tokio::sync::Mutex<Type>
+    (@form $V:tt [$($U:tt)?] $M:ident as $N:ident() -> async MutexGuard<$T:ty>) => {
+        $crate::project!(@fn $V [async $($U)?] $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> $crate::__tokio!(guard $T) {
+            $crate::__tokio!(lock ::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // polling, the member is a Future with Output = Type
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> poll $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+            cx: &mut ::core::task::Context<'_>,
+        ) -> ::core::task::Poll<$T> {
+            ::core::future::Future::poll(
+                unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) },
+                cx,
+            )
+        });
+    };
+
+    // getter, by clone
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> $T:ty) => {
+        $crate::project!(@get $V $U $M $N $T);
+    };
+    (@get $V:tt $U:tt $M:ident $N:ident $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&Self>) -> $T {
+            ::core::clone::Clone::clone(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // scoped, passes the projection to a closure
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> |Pin<&$T:ty>|) => {
+        $crate::project!(@fn $V $U $M $N[__R](
+            self: ::core::pin::Pin<&Self>,
+            f: impl ::core::ops::FnOnce(::core::pin::Pin<&$T>) -> __R,
+        ) -> __R {
+            f(unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.$M) })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> |Pin<&mut $T:ty>|) => {
+        $crate::project!(@fn $V $U $M $N[__R](
+            self: ::core::pin::Pin<&mut Self>,
+            f: impl ::core::ops::FnOnce(::core::pin::Pin<&mut $T>) -> __R,
+        ) -> __R {
+            f(unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> |&$T:ty|) => {
+        $crate::project!(@fn $V $U $M $N[__R](
+            self: ::core::pin::Pin<&Self>,
+            f: impl ::core::ops::FnOnce(&$T) -> __R,
+        ) -> __R {
+            f(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident() -> |&mut $T:ty|) => {
+        $crate::project!(@fn $V $U $M $N[__R](
+            self: ::core::pin::Pin<&mut Self>,
+            f: impl ::core::ops::FnOnce(&mut $T) -> __R,
+        ) -> __R {
+            f(unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M })
+        });
+    };
+
+    // sub-slice, indexed by a range
+    (@form $V:tt $U:tt $M:ident as $N:ident[..] -> Pin<&[$T:ty]>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+            range: ::core::ops::Range<usize>,
+        ) -> ::core::pin::Pin<&[$T]> {
+            unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.$M[range]) }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident[..] -> Pin<&mut [$T:ty]>) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+            range: ::core::ops::Range<usize>,
+        ) -> ::core::pin::Pin<&mut [$T]> {
+            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M[range]) }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident[..] -> &[$T:ty]) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&Self>,
+            range: ::core::ops::Range<usize>,
+        ) -> &[$T] {
+            &::core::pin::Pin::get_ref(self).$M[range]
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident[..] -> &mut [$T:ty]) => {
+        $crate::project!(@fn $V $U $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+            range: ::core::ops::Range<usize>,
+        ) -> &mut [$T] {
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M[range] }
+        });
+    };
+
+    // downcast, the member is a Box<dyn Any> or another pointer to a dyn Any
+    (@form $V:tt $U:tt
+        $M:ident as $N:ident<$G:ident>() -> downcast Option<Pin<&mut $T:ty>>
+    ) => {
+        $crate::project!(@fn $V $U $M $N[$G: ::core::any::Any](
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&mut $T>> {
+            let any: &mut dyn ::core::any::Any =
+                unsafe { &mut *::core::pin::Pin::get_unchecked_mut(self).$M };
+            ::core::option::Option::map(<dyn ::core::any::Any>::downcast_mut::<$T>(any), |value| {
+                unsafe { ::core::pin::Pin::new_unchecked(value) }
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<$G:ident>() -> downcast Option<Pin<&$T:ty>>) => {
+        $crate::project!(@fn $V $U $M $N[$G: ::core::any::Any](
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&$T>> {
+            let any: &dyn ::core::any::Any = &*::core::pin::Pin::get_ref(self).$M;
+            ::core::option::Option::map(<dyn ::core::any::Any>::downcast_ref::<$T>(any), |value| {
+                unsafe { ::core::pin::Pin::new_unchecked(value) }
+            })
+        });
+    };
+
+    // array element, indexed by a const generic parameter
+    (@form $V:tt $U:tt $M:ident as $N:ident<const $I:ident>() -> Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N[const $I: usize](
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| {
+                    $crate::__ArrayElement::<$I>::get_mut(&mut s.$M)
+                })
+            }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<const $I:ident>() -> Pin<&$T:ty>) => {
+        $crate::project!(@fn $V $U $M $N[const $I: usize](
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&$T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked(self, |s| $crate::__ArrayElement::<$I>::get(&s.$M))
+            }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<const $I:ident>() -> &mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N[const $I: usize](
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> &mut $T {
+            $crate::__ArrayElement::<$I>::get_mut(unsafe {
+                &mut ::core::pin::Pin::get_unchecked_mut(self).$M
+            })
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<const $I:ident>() -> &$T:ty) => {
+        $crate::project!(@fn $V $U $M $N[const $I: usize](self: ::core::pin::Pin<&Self>) -> &$T {
+            $crate::__ArrayElement::<$I>::get(&::core::pin::Pin::get_ref(self).$M)
+        });
+    };
+
+    // explicit lifetime for the receiver
+    (@form $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> Pin<&$R:lifetime mut $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N[$L](
+            self: ::core::pin::Pin<&$L mut Self>,
+        ) -> ::core::pin::Pin<&$R mut $T> {
+            $crate::__pin_check!(self);
+            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.$M) }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> Pin<&$R:lifetime $T:ty>) => {
+        $crate::project!(@fn $V $U $M $N[$L](
+            self: ::core::pin::Pin<&$L Self>,
+        ) -> ::core::pin::Pin<&$R $T> {
+            unsafe { ::core::pin::Pin::map_unchecked(self, |s| &s.$M) }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> &$R:lifetime mut $T:ty) => {
+        $crate::project!(@fn $V $U $M $N[$L](self: ::core::pin::Pin<&$L mut Self>) -> &$R mut $T {
+            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M }
+        });
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> &$R:lifetime $T:ty) => {
+        $crate::project!(@fn $V $U $M $N[$L](self: ::core::pin::Pin<&$L Self>) -> &$R $T {
+            &::core::pin::Pin::get_ref(self).$M
+        });
+    };
+
+    // custom mapping, the expression maps the member to the projected place
+    (@form $V:tt [unsafe] $M:ident |$m:ident| $($rest:tt)+) => {
+        $crate::project!(@map [$V $M $m] [] $($rest)+);
+    };
+    (@form $V:tt [] $M:ident |$m:ident| $($rest:tt)+) => {
+        $crate::project!(@error
+            "mappings of `", ::core::stringify!($M), "` must be declared `unsafe`"
+        );
+    };
+    (@map $G:tt [$($e:tt)+] as $N:ident() -> $($rest:tt)+) => {
+        $crate::project!(@mapped $G [$($e)+] $N $($rest)+);
+    };
+    (@map $G:tt [$($e:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::project!(@map $G [$($e)* $next] $($rest)*);
+    };
+    (@map [$V:tt $M:ident $m:ident] $e:tt) => {
+        $crate::project!(@error "missing `as function() -> Projection` after the mapping");
+    };
+    (@mapped [$V:tt $M:ident $m:ident] [$($e:tt)+] $N:ident Pin<&mut $T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::pin::Pin<&mut $T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked_mut(self, |s| {
+                    let $m = &mut s.$M;
+                    $($e)+
+                })
+            }
+        });
+    };
+    (@mapped [$V:tt $M:ident $m:ident] [$($e:tt)+] $N:ident Pin<&$T:ty>) => {
+        $crate::project!(@fn $V [unsafe] $M $N(
+            self: ::core::pin::Pin<&Self>,
+        ) -> ::core::pin::Pin<&$T> {
+            unsafe {
+                ::core::pin::Pin::map_unchecked(self, |s| {
+                    let $m = &s.$M;
+                    $($e)+
+                })
+            }
+        });
+    };
+    (@mapped [$V:tt $M:ident $m:ident] [$($e:tt)+] $N:ident &mut $T:ty) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: ::core::pin::Pin<&mut Self>) -> &mut $T {
+            let $m = unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).$M };
+            $($e)+
+        });
+    };
+    (@mapped [$V:tt $M:ident $m:ident] [$($e:tt)+] $N:ident &$T:ty) => {
+        $crate::project!(@fn $V [unsafe] $M $N(self: ::core::pin::Pin<&Self>) -> &$T {
+            let $m = &::core::pin::Pin::get_ref(self).$M;
+            $($e)+
+        });
+    };
+    (@mapped $G:tt $e:tt $N:ident $($rest:tt)*) => {
+        $crate::project!(@error
+            "mappings project to `Pin<&Type>`, `Pin<&mut Type>`, `&Type` or `&mut Type`, found `",
+            ::core::stringify!($($rest)*), "`"
+        );
+    };
+
+    // shared and mutable pair
+    (@form $V:tt $U:tt $M:ident as $N:ident(), $NM:ident() -> Pin<&(mut) $T:ty>) => {
+        $crate::project!(@form $V $U $M as $N() -> Pin<&$T>);
+        $crate::project!(@form $V $U $M as $NM() -> Pin<&mut $T>);
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident(), $NM:ident() -> &(mut) $T:ty) => {
+        $crate::project!(@form $V $U $M as $N() -> &$T);
+        $crate::project!(@form $V $U $M as $NM() -> &mut $T);
+    };
+    (@form $V:tt $U:tt $M:ident, $NM:ident() -> $($rest:tt)+) => {
+        $crate::project!(@form $V $U $M as $M(), $NM() -> $($rest)+);
+    };
+
+    // unnamed, the function is named like the member
+    (@form $V:tt $U:tt $M:ident -> $($rest:tt)+) => {
+        $crate::project!(@form $V $U $M as $M() -> $($rest)+);
+    };
+
+    // setter, by clone
+    (@form $V:tt $U:tt $M:ident as $N:ident(&$T:ty)) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&mut Self>, from: &$T) {
+            unsafe {
+                ::core::pin::Pin::get_unchecked_mut(self).$M = ::core::clone::Clone::clone(from);
+            }
+        });
+    };
+
+    // setter, by move
+    (@form $V:tt $U:tt $M:ident as $N:ident($T:ty)) => {
+        $crate::project!(@set $V $U $M $N $T);
+    };
+    (@set $V:tt $U:tt $M:ident $N:ident $T:ty) => {
+        $crate::project!(@fn $V $U $M $N(self: ::core::pin::Pin<&mut Self>, from: $T) {
+            unsafe {
+                ::core::pin::Pin::get_unchecked_mut(self).$M = from;
+            }
+        });
+    };
+
+    // diagnostics for malformed input, these must stay the last forms
+    (@form $V:tt $U:tt $M:ident as $N:ident -> $($rest:tt)*) => {
+        $crate::project!(@error
+            "missing `()` after the function name `", ::core::stringify!($N), "`"
+        );
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident<$L:lifetime>() -> $($rest:tt)*) => {
+        $crate::project!(@error
+            "with the lifetime `", ::core::stringify!($L), "` the projection must be a ",
+            "reference with an explicit lifetime, found `", ::core::stringify!($($rest)*), "`"
+        );
+    };
+    (@form $V:tt $U:tt $M:ident ($($from:tt)*)) => {
+        $crate::project!(@error
+            "setters need a function name, e.g. `", ::core::stringify!($M), " as set_",
+            ::core::stringify!($M), "(", ::core::stringify!($($from)*), ")`"
+        );
+    };
+    (@form $V:tt $U:tt $M:ident as $N:ident($($from:tt)+) -> $($rest:tt)*) => {
+        $crate::project!(@error
+            "setters have no return type, found `-> ", ::core::stringify!($($rest)*),
+            "` after `", ::core::stringify!($N), "(", ::core::stringify!($($from)+), ")`"
+        );
+    };
+    (@form $V:tt $U:tt $($input:tt)*) => {
+        $crate::project!(@error "invalid input `", ::core::stringify!($($input)*), "`");
+    };
+    (@error $($message:expr),+) => {
+        ::core::compile_error!(::core::concat!(
+            "project!: ", $($message,)+ "\n",
+            "expected one of:\n",
+            "  project!([pub] [unsafe] member as function() -> Projection)\n",
+            "  project!([pub] [unsafe] member -> Projection)\n",
+            "  project!([pub] [unsafe] member as function() -> |Projection|)\n",
+            "  project!([pub] [unsafe] member as function<'lifetime>() -> Projection)\n",
+            "  project!([pub] [unsafe] member as function(), function_mut() -> Pair)\n",
+            "  project!([pub] [unsafe] member, function_mut() -> Pair)\n",
+            "  project!([pub] [unsafe] member as function[..] -> Slice)\n",
+            "  project!([pub] unsafe member |binding| mapping as function() -> Projection)\n",
+            "  project!([pub] [unsafe] member as function<const INDEX>() -> Projection)\n",
+            "  project!([pub] [unsafe] member as function<Type>() -> downcast Option)\n",
+            "  project!([pub] [unsafe] member as function(From))\n",
+            "  project!([pub] unsafe member as function(From) -> uninit Pin<&mut Type>)\n",
+            "  project!([pub] [unsafe] member as function() -> Option if condition)\n",
+            "  project!([pub] not_pinned member as function() -> Reference)\n",
+            "  project!([pub] not_pinned member -> Reference)\n",
+            "  project!([pub] not_pinned member: Type)\n",
+            "  project!([pub] not_pinned member, function_mut() -> &(mut) Type)\n",
+            "  project!([pub] not_pinned member as function(Pin<&mut Self>))\n",
+            "  project!([pub] self as function() -> Pin<&mut Self>)\n",
+            "  project!([pub] self as function() -> Pin<&Self>)\n",
+            "  project!([pub] [unsafe] box member as function() -> Projection)\n",
+            "  project!([pub] [unsafe] box member as function(From))\n",
+            "  project!([pub] unsafe box member as function() -> into Type)\n",
+            "  project!([pub] [unsafe] rc member as function() -> Projection)\n",
+            "  project!([pub] [unsafe] arc member as function() -> Projection)\n",
+            "  project!([pub] unsafe promote member as function() -> Projection)\n",
+            "  project!([pub] unsafe drop member as function())\n",
+            "  project!([pub] safe member as function() -> Projection)\n",
+            "  project!([pub] safe member -> Projection)\n",
+            "  project!([pub] safe box member as function() -> into Type)\n",
+            "  project!([pub] atomic_waker member as function(&Waker))\n",
+            "  project!([pub] atomic_waker member as function())\n",
+            "each optionally followed by a `where` clause"
+        ));
+    };
+
+    // finds the `self` of the condition, the generated receiver must use this token
+    (@guard $G:tt $s:tt $($rest:tt)*) => {
+        $crate::project!(@guard_tt $G [$($rest)*] $s $s);
+    };
+    (@guard_tt $G:tt $rest:tt $s:tt self) => {
+        $crate::project!(@guarded $G $s);
+    };
+    (@guard_tt $G:tt [$($rest:tt)*] $s:tt ($($inner:tt)*)) => {
+        $crate::project!(@guard $G $($inner)* $($rest)*);
+    };
+    (@guard_tt $G:tt [$($rest:tt)*] $s:tt [$($inner:tt)*]) => {
+        $crate::project!(@guard $G $($inner)* $($rest)*);
+    };
+    (@guard_tt $G:tt [$($rest:tt)*] $s:tt {$($inner:tt)*}) => {
+        $crate::project!(@guard $G $($inner)* $($rest)*);
+    };
+    (@guard_tt $G:tt [$($rest:tt)*] $s:tt $t:tt) => {
+        $crate::project!(@guard $G $($rest)*);
+    };
+    (@guard [$V:tt $U:tt $M:ident $N:ident $R:tt [$($cond:tt)*]]) => {
+        $crate::project!(@error
+            "the condition `", ::core::stringify!($($cond)*), "` must refer to `self`"
+        );
+    };
+    (@guarded [$V:tt $U:tt $M:ident $N:ident [mut $T:ty] [$($cond:tt)*]] $s:tt) => {
+        $crate::project!(@fn $V $U $M $N(
+            $s: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&mut $T>> {
+            if $($cond)* {
+                ::core::option::Option::Some(unsafe {
+                    ::core::pin::Pin::map_unchecked_mut($s, |s| &mut s.$M)
+                })
+            } else {
+                ::core::option::Option::None
+            }
+        });
+    };
+    (@guarded [$V:tt $U:tt $M:ident $N:ident [$T:ty] [$($cond:tt)*]] $s:tt) => {
+        $crate::project!(@fn $V $U $M $N(
+            $s: ::core::pin::Pin<&Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&$T>> {
+            if $($cond)* {
+                ::core::option::Option::Some(unsafe {
+                    ::core::pin::Pin::map_unchecked($s, |s| &s.$M)
+                })
+            } else {
+                ::core::option::Option::None
+            }
+        });
+    };
+
+    // splits off the where clause, it is carried along with the visibility and stable name,
+    // scans up to eight tokens per step like `@batch`
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)*);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt $t1:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0 $t1);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt $t1:tt $t2:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0 $t1 $t2);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0 $t1 $t2 $t3);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0 $t1 $t2 $t3 $t4);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0 $t1 $t2 $t3 $t4 $t5);
+    };
+    (@where [$($V:tt)*] $U:tt [$($form:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt where $($W:tt)*) => {
+        $crate::project!(@form [$($V)* $($W)*] $U $($form)* $t0 $t1 $t2 $t3 $t4 $t5 $t6);
+    };
+    (@where $V:tt $U:tt [$($form:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $($rest:tt)*) => {
+        $crate::project!(@where $V $U [$($form)* $t0 $t1 $t2 $t3 $t4 $t5 $t6 $t7] $($rest)*);
+    };
+    (@where $V:tt $U:tt [$($form:tt)*] $($rest:tt)*) => {
+        $crate::project!(@form $V $U $($form)* $($rest)*);
+    };
+
+    // single declarations, collect the documentation, stable name and inlining and normalize
+    // visibility, tracing and unsafety
+    (@decl [$($D:tt)*] $S:tt $I:tt #[doc = $d:expr] $($rest:tt)*) => {
+        $crate::project!(@decl [$($D)* #[doc = $d]] $S $I $($rest)*);
+    };
+    (@decl $D:tt [] $I:tt #[stable($S:ident)] $($rest:tt)*) => {
+        $crate::project!(@decl $D [$S] $I $($rest)*);
+    };
+    (@decl $D:tt $S:tt [$O:tt #[inline]] #[inline] $($rest:tt)*) => {
+        $crate::project!(@decl $D $S [$O #[inline]] $($rest)*);
+    };
+    (@decl $D:tt $S:tt [$O:tt #[inline]] #[inline(always)] $($rest:tt)*) => {
+        $crate::project!(@decl $D $S [$O #[inline(always)]] $($rest)*);
+    };
+    (@decl $D:tt $S:tt [$O:tt #[inline]] #[inline(never)] $($rest:tt)*) => {
+        $crate::project!(@decl $D $S [$O #[inline(never)]] $($rest)*);
+    };
+    (@decl $D:tt $S:tt [$O:tt #[inline]] #[no_inline] $($rest:tt)*) => {
+        $crate::project!(@decl $D $S [$O] $($rest)*);
+    };
+    (@decl $D:tt $S:tt $I:tt $($rest:tt)*) => {
+        $crate::project!(@vis $D $S $I $($rest)*);
+    };
+    (@vis $D:tt $S:tt $I:tt $P:vis traced unsafe $M:ident $($rest:tt)*) => {
+        $crate::project!(@where [[$P] $S $D [traced $I]] [unsafe] [$M] $($rest)*);
+    };
+    (@vis $D:tt $S:tt $I:tt $P:vis traced $M:ident $($rest:tt)*) => {
+        $crate::project!(@where [[$P] $S $D [traced $I]] [] [$M] $($rest)*);
+    };
+    (@vis $D:tt $S:tt $I:tt $P:vis unsafe $M:ident $($rest:tt)*) => {
+        $crate::project!(@where [[$P] $S $D [$I]] [unsafe] [$M] $($rest)*);
+    };
+    (@vis $D:tt $S:tt $I:tt $P:vis $M:ident $($rest:tt)*) => {
+        $crate::project!(@where [[$P] $S $D [$I]] [] [$M] $($rest)*);
+    };
+    (@vis $D:tt $S:tt $I:tt $($input:tt)*) => {
+        $crate::project!(@error "invalid input `", ::core::stringify!($($input)*), "`");
+    };
+
+    // splits the input into declarations separated by `;` and feature groups, scans up to
+    // eight tokens per step to keep the recursion shallow for large batches
+    (@batch $O:tt [] feature $F:literal { $($group:tt)* } $($rest:tt)*) => {
+        #[cfg(feature = $F)]
+        $crate::project! { @batch $O [] $($group)* }
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)*);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0 $t1);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt $t2:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0 $t1 $t2);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0 $t1 $t2 $t3);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0 $t1 $t2 $t3 $t4);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0 $t1 $t2 $t3 $t4 $t5);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt ; $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $t0 $t1 $t2 $t3 $t4 $t5 $t6);
+        $crate::project!(@batch $O [] $($rest)*);
+    };
+    (@batch $O:tt [$($decl:tt)*] $t0:tt $t1:tt $t2:tt $t3:tt $t4:tt $t5:tt $t6:tt $t7:tt $($rest:tt)*) => {
+        $crate::project!(@batch $O [$($decl)* $t0 $t1 $t2 $t3 $t4 $t5 $t6 $t7] $($rest)*);
+    };
+    (@batch $O:tt []) => {};
+    (@batch $O:tt [$($decl:tt)*] $($rest:tt)*) => {
+        $crate::project!(@decl [] [] [$O #[inline]] $($decl)* $($rest)*);
+    };
+
+    // options preceding the declarations, the policy macro and the type named in examples
+    (@opts [$pol:tt $ex:tt] policy = $($P:ident)::+; $($input:tt)*) => {
+        $crate::project!(@opts [[$($P)::+] $ex] $($input)*);
+    };
+    (@opts [$pol:tt $ex:tt] example = $($input:tt)*) => {
+        $crate::project!(@example $pol [] $($input)*);
+    };
+    (@opts $O:tt $($input:tt)*) => {
+        $crate::project!(@batch $O [] $($input)*);
+    };
+    (@example $pol:tt [$($m:ident)*] $s:ident :: $($input:tt)*) => {
+        $crate::project!(@example $pol [$($m)* $s] $($input)*);
+    };
+    (@example $pol:tt [$($m:ident)+] $E:ident $(<$($A:ty),* $(,)?>)?; $($input:tt)*) => {
+        $crate::project!(@opts [$pol [[$($m)+] [$E $(<$($A),*>)?]]] $($input)*);
+    };
+    (@example $pol:tt $m:tt $($input:tt)*) => {
+        $crate::project!(@error "`example = path::to::Type;` needs the full path of the type");
+    };
+
+    // entry point
+    ($($input:tt)*) => {
+        $crate::project!(@opts [[] []] $($input)*);
     };
 }
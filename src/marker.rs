@@ -0,0 +1,125 @@
+//! Helpers for controlling `Unpin`.
+
+/// Defines a struct that is guaranteed to be `!Unpin`.
+///
+/// The syntax is:
+///
+/// ```text
+/// not_unpin! {
+///     [$ATTRIBUTES] [pub] struct $NAME[<$GENERICS>] [where $BOUNDS] {
+///         $FIELDS
+///     }
+/// }
+/// ```
+///
+/// The struct is emitted as written with an additional
+/// `_pinned: core::marker::PhantomPinned` member in front of the user supplied members.
+/// Constructors have to initialize it with `PhantomPinned`. Since the member is private,
+/// such a struct can only be constructed from within its defining module.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::not_unpin;
+/// use std::marker::PhantomPinned;
+///
+/// not_unpin! {
+///     pub struct Node<T> {
+///         next: *const Node<T>,
+///         value: T,
+///     }
+/// }
+///
+/// let node = Node { _pinned: PhantomPinned, next: std::ptr::null(), value: 42 };
+/// assert_eq!(node.value, 42);
+/// ```
+///
+/// Types defined this way are not `Unpin`:
+///
+/// ```compile_fail
+/// # use pin_projections::not_unpin;
+/// not_unpin! {
+///     struct Node {
+///         value: u32,
+///     }
+/// }
+///
+/// fn is_unpin<T: Unpin>() {}
+/// is_unpin::<Node>();
+/// ```
+#[macro_export]
+macro_rules! not_unpin {
+    (@munch [$(#[$attr:meta])*] [$P:vis] $N:ident [$($generics:tt)*] { $($fields:tt)* }) => {
+        $(#[$attr])*
+        $P struct $N $($generics)* {
+            _pinned: ::core::marker::PhantomPinned,
+            $($fields)*
+        }
+    };
+    (@munch [$(#[$attr:meta])*] [$P:vis] $N:ident [$($generics:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::not_unpin!(@munch [$(#[$attr])*] [$P] $N [$($generics)* $next] $($rest)*);
+    };
+    ($(#[$attr:meta])* $P:vis struct $N:ident $($rest:tt)*) => {
+        $crate::not_unpin!(@munch [$(#[$attr])*] [$P] $N [] $($rest)*);
+    };
+}
+
+/// Asserts at compile time that a type is `Unpin`.
+///
+/// Can be used wherever items are allowed. The type must not depend on generic parameters.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::assert_unpin;
+///
+/// struct Counter(u64);
+/// assert_unpin!(Counter);
+/// ```
+///
+/// ```compile_fail
+/// # use pin_projections::assert_unpin;
+/// assert_unpin!(std::marker::PhantomPinned);
+/// ```
+#[macro_export]
+macro_rules! assert_unpin {
+    ($T:ty) => {
+        const _: () = {
+            fn assert_unpin<T: ?Sized + ::core::marker::Unpin>() {}
+            let _ = assert_unpin::<$T>;
+        };
+    };
+}
+
+/// Asserts at compile time that a type is not `Unpin`.
+///
+/// Can be used wherever items are allowed. The type must not depend on generic parameters.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::assert_not_unpin;
+///
+/// struct Node(std::marker::PhantomPinned);
+/// assert_not_unpin!(Node);
+/// ```
+///
+/// ```compile_fail
+/// # use pin_projections::assert_not_unpin;
+/// assert_not_unpin!(u64);
+/// ```
+#[macro_export]
+macro_rules! assert_not_unpin {
+    ($T:ty) => {
+        const _: () = {
+            // When T is Unpin both impls apply and the inferred type parameter is ambiguous.
+            trait AmbiguousIfUnpin<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfUnpin<()> for T {}
+            struct Invalid;
+            impl<T: ?Sized + ::core::marker::Unpin> AmbiguousIfUnpin<Invalid> for T {}
+            let _ = <$T as AmbiguousIfUnpin<_>>::some_item;
+        };
+    };
+}
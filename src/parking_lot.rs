@@ -0,0 +1,78 @@
+//! Lock projections for `parking_lot::Mutex` and `parking_lot::RwLock` members.
+//!
+//! With the `parking_lot` feature the `parking_lot` forms of `project!` call into
+//! `::parking_lot`, the crate using them must depend on `parking_lot`.
+
+/// Calls `parking_lot` for the `parking_lot` forms.
+///
+/// ```
+/// use parking_lot::{Mutex, RwLock};
+/// use pin_projections::project;
+///
+/// struct Shared {
+///     state: Mutex<u32>,
+///     config: RwLock<String>,
+/// }
+///
+/// impl Shared {
+///     project! {
+///         state as lock_state() -> parking_lot MutexGuard<u32>;
+///         state as try_lock_state() -> parking_lot Option<MutexGuard<u32>>;
+///         config as read_config() -> parking_lot RwLockReadGuard<String>;
+///         config as write_config() -> parking_lot RwLockWriteGuard<String>;
+///     }
+/// }
+///
+/// let shared = Box::pin(Shared { state: Mutex::new(1), config: RwLock::new("a".into()) });
+/// let mut state = shared.as_ref().lock_state();
+/// *state += 1;
+/// assert!(shared.as_ref().try_lock_state().is_none());
+/// drop(state);
+/// assert_eq!(*shared.as_ref().try_lock_state().unwrap(), 2);
+/// shared.as_ref().write_config().push('b');
+/// assert_eq!(*shared.as_ref().read_config(), "ab");
+/// ```
+#[cfg(feature = "parking_lot")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parking_lot {
+    (guard MutexGuard $T:ty) => {
+        ::parking_lot::MutexGuard<'_, $T>
+    };
+    (guard RwLockReadGuard $T:ty) => {
+        ::parking_lot::RwLockReadGuard<'_, $T>
+    };
+    (guard RwLockWriteGuard $T:ty) => {
+        ::parking_lot::RwLockWriteGuard<'_, $T>
+    };
+    (lock $member:expr) => {
+        ::parking_lot::Mutex::lock(&$member)
+    };
+    (try_lock $member:expr) => {
+        ::parking_lot::Mutex::try_lock(&$member)
+    };
+    (read $member:expr) => {
+        ::parking_lot::RwLock::read(&$member)
+    };
+    (try_read $member:expr) => {
+        ::parking_lot::RwLock::try_read(&$member)
+    };
+    (write $member:expr) => {
+        ::parking_lot::RwLock::write(&$member)
+    };
+    (try_write $member:expr) => {
+        ::parking_lot::RwLock::try_write(&$member)
+    };
+}
+
+#[cfg(not(feature = "parking_lot"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parking_lot {
+    (guard $G:ident $T:ty) => {
+        ()
+    };
+    ($op:ident $member:expr) => {
+        ::core::compile_error!("project!: `parking_lot` locks need the `parking_lot` feature")
+    };
+}
@@ -0,0 +1,159 @@
+//! Struct definitions together with their projections.
+
+/// Defines a struct and the projections of its marked members.
+///
+/// The syntax is:
+///
+/// ```text
+/// pin_struct! {
+///     [$ATTRIBUTES] [pub] struct $NAME[<$GENERIC[: $BOUND], ...>] [where $TYPE: $BOUND, ...] {
+///         [$ATTRIBUTES] [$MARKER] [pub] $MEMBER: $TYPE,
+///         ...
+///     }
+/// }
+/// ```
+///
+/// The struct is defined as written, without the markers. MARKER is one of:
+///  - `#[pin]`: structurally pinned, generates `fn member(self: Pin<&Self>) -> Pin<&T>`.
+///  - `#[pin(mut as $FUNCTION)]`: structurally pinned, additionally generates
+///    `fn $FUNCTION(self: Pin<&mut Self>) -> Pin<&mut T>`.
+///  - `#[unpin]`: not structurally pinned, generates `fn member(self: Pin<&Self>) -> &T`.
+///  - `#[unpin(mut as $FUNCTION)]`: not structurally pinned, additionally generates
+///    `fn $FUNCTION(self: Pin<&mut Self>) -> &mut T`.
+///
+/// Members without a marker get no projections. The projections have the same visibility as
+/// the member. This is the declarative counterpart of the `PinProjections` derive, the names
+/// of the mutable projections must be given as `macro_rules!` can't build identifiers.
+/// Generics are limited to type parameters with at most one bound each, use `project!`
+/// directly for anything more complex.
+///
+/// # SAFETY
+///
+/// The same rules as for [`project!`](crate::project) apply.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::pin_struct;
+/// use std::future::{ready, Future};
+/// use std::pin::Pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// pin_struct! {
+///     /// Counts the polls of a future.
+///     pub struct Counted<F: Future> {
+///         #[pin(mut as future_mut)]
+///         future: F,
+///         #[unpin(mut as polls_mut)]
+///         pub polls: u32,
+///         untouched: bool,
+///     }
+/// }
+///
+/// impl<F: Future> Future for Counted<F> {
+///     type Output = F::Output;
+///
+///     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+///         *self.as_mut().polls_mut() += 1;
+///         self.future_mut().poll(cx)
+///     }
+/// }
+///
+/// let mut counted = Box::pin(Counted { future: ready(42), polls: 0, untouched: true });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// assert_eq!(counted.as_mut().poll(&mut cx), Poll::Ready(42));
+/// assert_eq!(*counted.as_ref().polls(), 1);
+/// let _: Pin<&_> = counted.as_ref().future();
+/// ```
+#[macro_export]
+macro_rules! pin_struct {
+    // markers of the current member
+    (@member $H:tt $F:tt $J:tt $A:tt [] #[pin] $($rest:tt)*) => {
+        $crate::pin_struct!(@member $H $F $J $A [pin] $($rest)*);
+    };
+    (@member $H:tt $F:tt $J:tt $A:tt [] #[pin(mut as $NM:ident)] $($rest:tt)*) => {
+        $crate::pin_struct!(@member $H $F $J $A [pin $NM] $($rest)*);
+    };
+    (@member $H:tt $F:tt $J:tt $A:tt [] #[unpin] $($rest:tt)*) => {
+        $crate::pin_struct!(@member $H $F $J $A [unpin] $($rest)*);
+    };
+    (@member $H:tt $F:tt $J:tt $A:tt [] #[unpin(mut as $NM:ident)] $($rest:tt)*) => {
+        $crate::pin_struct!(@member $H $F $J $A [unpin $NM] $($rest)*);
+    };
+    (@member $H:tt $F:tt $J:tt $A:tt $K:tt #[pin $($args:tt)*] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "pin_struct!: invalid marker `#[pin", ::core::stringify!($($args)*), "]`, ",
+            "expected one of `#[pin]`, `#[pin(mut as function)]`, `#[unpin]` or ",
+            "`#[unpin(mut as function)]` per member"
+        ));
+    };
+    (@member $H:tt $F:tt $J:tt $A:tt $K:tt #[unpin $($args:tt)*] $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "pin_struct!: invalid marker `#[unpin", ::core::stringify!($($args)*), "]`, ",
+            "expected one of `#[pin]`, `#[pin(mut as function)]`, `#[unpin]` or ",
+            "`#[unpin(mut as function)]` per member"
+        ));
+    };
+    (@member $H:tt $F:tt $J:tt [$($A:tt)*] $K:tt #[$attr:meta] $($rest:tt)*) => {
+        $crate::pin_struct!(@member $H $F $J [$($A)* #[$attr]] $K $($rest)*);
+    };
+
+    // the member itself
+    (
+        @member $H:tt [$($F:tt)*] [$($J:tt)*] [$($A:tt)*] $K:tt
+        $V:vis $M:ident : $T:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::pin_struct!(
+            @member $H [$($F)* $($A)* $V $M: $T,] [$($J)* {$K $V $M $T}] [] []
+            $($($rest)*)?
+        );
+    };
+
+    // all members collected
+    (
+        @member [[$($head:tt)*] [$($G:tt)*] [$($S:tt)*] [$($W:tt)*]]
+        [$($F:tt)*] [$($J:tt)*] [] []
+    ) => {
+        $($head)* $($W)* {
+            $($F)*
+        }
+
+        impl $($G)* $($S)* $($W)* {
+            $($crate::pin_struct!(@project $J);)*
+        }
+    };
+
+    (@project {[] $V:vis $M:ident $T:ty}) => {};
+    (@project {[pin] $V:vis $M:ident $T:ty}) => {
+        $crate::project!($V $M -> Pin<&$T>);
+    };
+    (@project {[pin $NM:ident] $V:vis $M:ident $T:ty}) => {
+        $crate::project!($V $M, $NM() -> Pin<&(mut) $T>);
+    };
+    (@project {[unpin] $V:vis $M:ident $T:ty}) => {
+        $crate::project!($V $M -> &$T);
+    };
+    (@project {[unpin $NM:ident] $V:vis $M:ident $T:ty}) => {
+        $crate::project!($V $M, $NM() -> &(mut) $T);
+    };
+
+    // entry point
+    (
+        $(#[$attr:meta])*
+        $P:vis struct $N:ident $(<$($G:ident $(: $B:path)?),* $(,)?>)?
+        $(where $($WT:ty : $WB:path),+ $(,)?)?
+        { $($members:tt)* }
+    ) => {
+        $crate::pin_struct!(
+            @member
+            [
+                [$(#[$attr])* $P struct $N $(<$($G $(: $B)?),*>)?]
+                [$(<$($G $(: $B)?),*>)?]
+                [$N $(<$($G),*>)?]
+                [$(where $($WT: $WB),+)?]
+            ]
+            [] [] [] []
+            $($members)*
+        );
+    };
+}
@@ -0,0 +1,94 @@
+//! Drop implementations for types with pin projections.
+
+use core::pin::Pin;
+
+/// Drop for pinned objects.
+///
+/// Types with structurally pinned members must not move these members in their `Drop`
+/// implementation. Implementing this trait with the [`pinned_drop!`] macro generates the
+/// `Drop` implementation that hands out `Pin<&mut Self>` instead of `&mut Self`.
+///
+/// [`pinned_drop!`]: macro@crate::pinned_drop
+pub trait PinnedDrop {
+    /// Called from the generated `Drop::drop()`.
+    fn drop_pinned(self: Pin<&mut Self>);
+}
+
+/// Implements `Drop` in terms of [`PinnedDrop`].
+///
+/// The syntax is:
+///
+/// ```text
+/// pinned_drop! {
+///     impl[<$GENERICS>] PinnedDrop for $TYPE [where $BOUNDS] {
+///         fn drop_pinned(self: Pin<&mut Self>) {
+///             ...
+///         }
+///     }
+/// }
+/// ```
+///
+/// The `PinnedDrop` implementation is emitted as written together with a `Drop`
+/// implementation for the same type which calls it.
+///
+/// # SAFETY
+///
+/// The `unsafe` to pin `self` is encapsulated in the generated `Drop::drop()`. This is sound
+/// because the object is never used again after `drop()` returns.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::{pinned_drop, project};
+/// use std::cell::Cell;
+/// use std::pin::Pin;
+/// use std::rc::Rc;
+///
+/// struct Guard<T> {
+///     value: T,
+///     dropped: Rc<Cell<bool>>,
+/// }
+///
+/// impl<T> Guard<T> {
+///     project!(value -> Pin<&mut T>);
+/// }
+///
+/// pinned_drop! {
+///     impl<T> PinnedDrop for Guard<T> {
+///         fn drop_pinned(self: Pin<&mut Self>) {
+///             self.dropped.set(true);
+///             let _value: Pin<&mut T> = self.value();
+///         }
+///     }
+/// }
+///
+/// let dropped = Rc::new(Cell::new(false));
+/// drop(Box::pin(Guard { value: 42, dropped: dropped.clone() }));
+/// assert!(dropped.get());
+/// ```
+#[macro_export]
+macro_rules! pinned_drop {
+    (@target [$($generics:tt)*] [$($target:tt)*] $body:tt) => {
+        impl $($generics)* $crate::pinned_drop::PinnedDrop for $($target)* $body
+
+        impl $($generics)* ::core::ops::Drop for $($target)* {
+            fn drop(&mut self) {
+                $crate::pinned_drop::PinnedDrop::drop_pinned(
+                    unsafe { ::core::pin::Pin::new_unchecked(self) }
+                );
+            }
+        }
+    };
+    (@target [$($generics:tt)*] [$($target:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::pinned_drop!(@target [$($generics)*] [$($target)* $next] $($rest)+);
+    };
+    (@generics [$($generics:tt)*] PinnedDrop for $($rest:tt)+) => {
+        $crate::pinned_drop!(@target [$($generics)*] [] $($rest)+);
+    };
+    (@generics [$($generics:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::pinned_drop!(@generics [$($generics)* $next] $($rest)+);
+    };
+    (impl $($rest:tt)+) => {
+        $crate::pinned_drop!(@generics [] $($rest)+);
+    };
+}
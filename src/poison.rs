@@ -0,0 +1,128 @@
+//! Poisoning of pinned members when a mutation panics.
+
+use core::fmt;
+use core::pin::Pin;
+
+/// Error returned when accessing a poisoned [`PinPoison`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Poisoned;
+
+impl fmt::Display for Poisoned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("pinned value poisoned by a panic during an update")
+    }
+}
+
+impl std::error::Error for Poisoned {}
+
+/// A structurally pinned value that is poisoned when a panic unwinds out of an update.
+///
+/// Updates go through [`PinPoison::update()`] which marks the value as poisoned when the
+/// update closure panics. After that all accessors return `Err(Poisoned)` until the poison is
+/// cleared, a half mutated value can't be observed silently after a recovered panic.
+///
+/// Members of this type are projected with the `poisoned` forms of
+/// [`project!`](crate::project).
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::poison::{PinPoison, Poisoned};
+/// use pin_projections::project;
+/// use std::panic::{catch_unwind, AssertUnwindSafe};
+/// use std::pin::Pin;
+///
+/// struct Example {
+///     counter: PinPoison<u32>,
+/// }
+///
+/// impl Example {
+///     project!(counter -> poisoned Pin<&u32>);
+///     project!(counter as update_counter() -> poisoned |Pin<&mut u32>|);
+/// }
+///
+/// let mut example = Box::pin(Example { counter: PinPoison::new(1) });
+/// example.as_mut().update_counter(|mut counter| *counter += 1).unwrap();
+/// assert_eq!(*example.as_ref().counter().unwrap(), 2);
+///
+/// let result = catch_unwind(AssertUnwindSafe(|| {
+///     example.as_mut().update_counter(|mut counter| {
+///         *counter += 1;
+///         panic!("half way");
+///     })
+/// }));
+/// assert!(result.is_err());
+/// assert_eq!(example.as_ref().counter(), Err(Poisoned));
+/// ```
+#[derive(Debug, Default)]
+pub struct PinPoison<T: ?Sized> {
+    poisoned: bool,
+    value: T,
+}
+
+impl<T> PinPoison<T> {
+    /// Creates a new not poisoned value.
+    pub const fn new(value: T) -> Self {
+        PinPoison {
+            poisoned: false,
+            value,
+        }
+    }
+}
+
+impl<T: ?Sized> PinPoison<T> {
+    /// Returns `true` when an update panicked.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    /// Clears the poison, the value is accessible again as left behind by the panic.
+    pub fn clear_poison(self: Pin<&mut Self>) {
+        unsafe { Pin::get_unchecked_mut(self).poisoned = false };
+    }
+
+    /// Projects to the pinned value unless poisoned.
+    pub fn get(self: Pin<&Self>) -> Result<Pin<&T>, Poisoned> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
+        Ok(unsafe { Pin::map_unchecked(self, |s| &s.value) })
+    }
+
+    /// Projects to the mutable pinned value unless poisoned.
+    ///
+    /// Mutations through the returned reference are not guarded, use
+    /// [`update()`](Self::update) for these.
+    pub fn get_mut(self: Pin<&mut Self>) -> Result<Pin<&mut T>, Poisoned> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
+        Ok(unsafe { Pin::map_unchecked_mut(self, |s| &mut s.value) })
+    }
+
+    /// Calls `f` with the mutable pinned value and returns its result.
+    ///
+    /// When `f` panics the value becomes poisoned. Returns `Err(Poisoned)` without calling
+    /// `f` when the value is already poisoned.
+    pub fn update<R>(
+        self: Pin<&mut Self>,
+        f: impl FnOnce(Pin<&mut T>) -> R,
+    ) -> Result<R, Poisoned> {
+        struct Guard<'a>(&'a mut bool);
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                *self.0 = true;
+            }
+        }
+
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+        if this.poisoned {
+            return Err(Poisoned);
+        }
+        let guard = Guard(&mut this.poisoned);
+        let result = f(unsafe { Pin::new_unchecked(&mut this.value) });
+        core::mem::forget(guard);
+        Ok(result)
+    }
+}
@@ -0,0 +1,15 @@
+//! Organization specific policies for generated projections.
+//!
+//! `project!(policy = path::to::macro; ...)` passes every generated function through `__policy!`
+//! with the options of the invocation, without a policy the function is emitted unchanged.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __policy {
+    ([[] $example:tt] $item:item) => {
+        $item
+    };
+    ([[$($policy:tt)+] $example:tt] $item:item) => {
+        $($policy)+! { $item }
+    };
+}
@@ -0,0 +1,75 @@
+//! Raw pointer and member offsets of pinned objects.
+
+/// Defines a struct holding the address of a pinned object and the offsets of its members.
+///
+/// The syntax is:
+///
+/// ```text
+/// raw_parts! {
+///     [$ATTRIBUTES] [pub] struct $PARTS for $HOST { $MEMBER, ... }
+/// }
+/// ```
+///
+/// This defines the struct PARTS with a `base: *const $HOST` member and one `usize` member
+/// per MEMBER holding its offset from `base`. The associated constant `$PARTS::OFFSETS` lists
+/// the names and offsets in declaration order. HOST gets a method
+/// `fn raw_parts(self: Pin<&Self>) -> $PARTS` with the same visibility as PARTS.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::raw_parts;
+///
+/// #[repr(C)]
+/// struct Descriptor {
+///     len: u32,
+///     flags: u32,
+///     addr: u64,
+/// }
+///
+/// raw_parts! {
+///     pub struct DescriptorParts for Descriptor { len, addr }
+/// }
+///
+/// let desc = Box::pin(Descriptor { len: 4096, flags: 0, addr: 0x1000 });
+/// let parts = desc.as_ref().raw_parts();
+/// assert_eq!(parts.base, &*desc as *const Descriptor);
+/// assert_eq!((parts.len, parts.addr), (0, 8));
+/// assert_eq!(DescriptorParts::OFFSETS, [("len", 0), ("addr", 8)]);
+/// ```
+#[macro_export]
+macro_rules! raw_parts {
+    (
+        $(#[$attr:meta])*
+        $P:vis struct $PARTS:ident for $HOST:ty { $($M:ident),* $(,)? }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        $P struct $PARTS {
+            /// Address of the object.
+            pub base: *const $HOST,
+            $(
+                #[doc = ::core::concat!("Offset of `", ::core::stringify!($M), "` from `base`.")]
+                pub $M: usize,
+            )*
+        }
+
+        impl $PARTS {
+            /// Names and offsets of the members in declaration order.
+            pub const OFFSETS: [(&'static str, usize); <[&str]>::len(&[$(::core::stringify!($M)),*])] = [
+                $((::core::stringify!($M), ::core::mem::offset_of!($HOST, $M)),)*
+            ];
+        }
+
+        impl $HOST {
+            /// Returns the address of `self` together with the offsets of its members.
+            #[inline]
+            $P fn raw_parts(self: ::core::pin::Pin<&Self>) -> $PARTS {
+                $PARTS {
+                    base: ::core::pin::Pin::get_ref(self) as *const $HOST,
+                    $($M: ::core::mem::offset_of!($HOST, $M),)*
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,49 @@
+//! Markers for counting generated projections.
+//!
+//! With the `report` feature every function generated by `project!` embeds a marker string
+//! `"\0pin-projections-report:$MODULE::$FUNCTION\0"` in the object code. The
+//! `pin-projections-report` example scans compiled artifacts for these markers and prints
+//! how many projections each module generates.
+//!
+//! The counts are per module and not per type, `project!` is invoked inside an `impl` block
+//! and doesn't know the name of `Self`. Types declared in their own module are counted
+//! separately.
+
+/// Copies a string into a byte array, used to build the markers at compile time.
+#[cfg(feature = "report")]
+pub const fn marker<const N: usize>(s: &str) -> [u8; N] {
+    let bytes = s.as_bytes();
+    let mut array = [0; N];
+    let mut i = 0;
+    while i < N {
+        array[i] = bytes[i];
+        i += 1;
+    }
+    array
+}
+
+#[cfg(feature = "report")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __report {
+    ($N:ident) => {
+        const _: () = {
+            const MARKER: &str = ::core::concat!(
+                "\0pin-projections-report:",
+                ::core::module_path!(),
+                "::",
+                ::core::stringify!($N),
+                "\0"
+            );
+            #[used]
+            static REPORT: [u8; MARKER.len()] = $crate::__report_marker(MARKER);
+        };
+    };
+}
+
+#[cfg(not(feature = "report"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __report {
+    ($N:ident) => {};
+}
@@ -0,0 +1,56 @@
+//! Pinning on the stack.
+
+/// Pins values on the stack.
+///
+/// The syntax is:
+///
+/// ```text
+/// stack_pin!(let $NAME = $EXPRESSION; ...);
+/// stack_pin!($NAME, ...);
+/// ```
+///
+/// The first form moves the result of EXPRESSION into a local and shadows it with a
+/// `Pin<&mut T>` of the same NAME. The second form does the same for existing local
+/// variables. In both cases the unpinned value can not be accessed anymore, which makes this
+/// safe.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::{project, stack_pin};
+/// use std::pin::Pin;
+///
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// impl Counter {
+///     project!(count -> &mut u32);
+/// }
+///
+/// stack_pin!(let counter = Counter { count: 0 });
+/// *counter.as_mut().count() += 1;
+///
+/// let other = Counter { count: 41 };
+/// stack_pin!(other);
+/// *other.as_mut().count() += 1;
+///
+/// assert_eq!(counter.count + other.count, 43);
+/// ```
+#[macro_export]
+macro_rules! stack_pin {
+    ($(let $N:ident = $E:expr);+ $(;)?) => {
+        $(
+            let mut $N = $E;
+            #[allow(unused_mut)]
+            let mut $N = unsafe { ::core::pin::Pin::new_unchecked(&mut $N) };
+        )+
+    };
+    ($($N:ident),+ $(,)?) => {
+        $(
+            let mut $N = $N;
+            #[allow(unused_mut)]
+            let mut $N = unsafe { ::core::pin::Pin::new_unchecked(&mut $N) };
+        )+
+    };
+}
@@ -0,0 +1,83 @@
+//! Projections into type erased, tagged storage.
+
+/// Defines a projection that reinterprets tagged storage as a registered type.
+///
+/// Projections are written inside the `impl` block of a struct.
+///
+/// The syntax is:
+///
+/// ```text
+/// project_tagged!([pub] $STORAGE by $TAG as $FUNCTION {
+///     $PATTERN => $TYPE,
+///     ...
+/// })
+/// ```
+///
+/// The parameters are:
+///  - **STORAGE:** member holding the payload, usually a byte array.
+///  - **TAG:** member describing which type is stored.
+///  - **FUNCTION:** name of the generated projection.
+///  - **PATTERN => TYPE:** the registry, which TYPE is stored when TAG matches PATTERN.
+///
+/// This generates `unsafe fn $FUNCTION<T: 'static>(self: Pin<&mut Self>) -> Option<Pin<&mut T>>`.
+/// It returns `None` unless the current TAG maps to `T` in the registry and STORAGE is big
+/// enough and suitably aligned for a `T`.
+///
+/// # SAFETY
+///
+/// The caller must ensure that STORAGE holds an initialized `T` whenever TAG says so. The
+/// payload is structurally pinned, it must not be moved or overwritten while pinned.
+///
+/// # Example
+///
+/// ```
+/// use pin_projections::project_tagged;
+/// use std::mem::MaybeUninit;
+///
+/// #[repr(C, align(8))]
+/// struct Slot {
+///     storage: [MaybeUninit<u8>; 16],
+///     tag: u8,
+/// }
+///
+/// impl Slot {
+///     project_tagged!(pub storage by tag as project_as {
+///         1 => u64,
+///         2 => [u32; 4],
+///     });
+/// }
+///
+/// let mut slot = Box::pin(Slot { storage: [MaybeUninit::new(0); 16], tag: 1 });
+/// unsafe {
+///     *slot.as_mut().project_as::<u64>().unwrap() = 42;
+///     assert_eq!(*slot.as_mut().project_as::<u64>().unwrap(), 42);
+///     assert!(slot.as_mut().project_as::<[u32; 4]>().is_none());
+///     assert!(slot.as_mut().project_as::<u32>().is_none());
+/// }
+/// ```
+#[macro_export]
+macro_rules! project_tagged {
+    ($P:vis $S:ident by $TAG:ident as $N:ident { $($V:pat => $T:ty),* $(,)? }) => {
+        #[inline]
+        $P unsafe fn $N<T: 'static>(
+            self: ::core::pin::Pin<&mut Self>,
+        ) -> ::core::option::Option<::core::pin::Pin<&mut T>> {
+            let this = unsafe { ::core::pin::Pin::get_unchecked_mut(self) };
+            #[allow(unreachable_patterns)]
+            let registered = match this.$TAG {
+                $($V => ::core::any::TypeId::of::<$T>() == ::core::any::TypeId::of::<T>(),)*
+                _ => false,
+            };
+            let storage = ::core::ptr::addr_of_mut!(this.$S);
+            if !registered
+                || ::core::mem::size_of::<T>() > ::core::mem::size_of_val(&this.$S)
+                || (storage as *mut u8).align_offset(::core::mem::align_of::<T>()) != 0
+            {
+                return ::core::option::Option::None;
+            }
+            ::core::option::Option::Some(unsafe {
+                ::core::pin::Pin::new_unchecked(&mut *(storage as *mut T))
+            })
+        }
+    };
+}
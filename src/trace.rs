@@ -0,0 +1,47 @@
+//! Tracing of projection calls.
+//!
+//! With the `tracing` feature the `traced` projections of `project!` emit a
+//! `::tracing::trace!` event on every call, the crate using them must depend on `tracing`.
+//! The event names the member, the projection, the type of `Self` and the caller. Async
+//! functions can't track their caller, for these the caller is the projection itself.
+
+/// Emits the trace event of the `traced` forms.
+///
+/// ```
+/// use pin_projections::project;
+/// use std::pin::Pin;
+///
+/// struct Connection {
+///     state: u32,
+/// }
+///
+/// impl Connection {
+///     project!(pub traced state as state_mut() -> Pin<&mut u32>);
+/// }
+///
+/// let mut connection = Box::pin(Connection { state: 0 });
+/// *connection.as_mut().state_mut() += 1;
+/// assert_eq!(connection.state, 1);
+/// ```
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace {
+    ($M:tt $N:ident) => {
+        ::tracing::trace!(
+            member = ::core::stringify!($M),
+            projection = ::core::stringify!($N),
+            self_type = ::core::any::type_name::<Self>(),
+            caller = %::core::panic::Location::caller(),
+        )
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace {
+    ($($input:tt)*) => {
+        ::core::compile_error!("project!: `traced` needs the `tracing` feature")
+    };
+}
@@ -0,0 +1,32 @@
+//! Access to the members of unions.
+
+use core::mem::ManuallyDrop;
+
+/// Union members holding a `T`, either as `ManuallyDrop<T>` or as a `Copy` type directly.
+pub trait UnionField<T: ?Sized> {
+    /// Returns the held value.
+    fn get(&self) -> &T;
+
+    /// Returns the held value for writing.
+    fn get_mut(&mut self) -> &mut T;
+}
+
+impl<T: ?Sized> UnionField<T> for ManuallyDrop<T> {
+    fn get(&self) -> &T {
+        self
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: Copy> UnionField<T> for T {
+    fn get(&self) -> &T {
+        self
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self
+    }
+}
@@ -0,0 +1,45 @@
+//! Accessors for `futures::task::AtomicWaker` members.
+//!
+//! With the `futures` feature the `atomic_waker` forms of `project!` call into
+//! `::futures::task::AtomicWaker`, the crate using them must depend on `futures`.
+
+/// Calls `AtomicWaker` for the `atomic_waker` forms.
+///
+/// ```
+/// use futures::task::AtomicWaker;
+/// use pin_projections::project;
+/// use std::task::Waker;
+///
+/// struct Event {
+///     waker: AtomicWaker,
+/// }
+///
+/// impl Event {
+///     project!(atomic_waker waker as register(&Waker));
+///     project!(atomic_waker waker as wake());
+/// }
+///
+/// let event = Box::pin(Event { waker: AtomicWaker::new() });
+/// event.as_ref().register(Waker::noop());
+/// event.as_ref().wake();
+/// ```
+#[cfg(feature = "futures")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __atomic_waker {
+    (register $member:expr, $waker:expr) => {
+        ::futures::task::AtomicWaker::register(&$member, $waker)
+    };
+    (wake $member:expr) => {
+        ::futures::task::AtomicWaker::wake(&$member)
+    };
+}
+
+#[cfg(not(feature = "futures"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __atomic_waker {
+    ($($input:tt)*) => {
+        ::core::compile_error!("project!: `atomic_waker` needs the `futures` feature")
+    };
+}